@@ -0,0 +1,48 @@
+// Multiplication of long values by schoolbook and by Karatsuba.
+//
+// Run with `cargo bench --bench mul`.  Every timing goes through
+// `Digits::mul_with_threshold`, so it measures the crate's own multiplication even
+// when the `num-bigint` feature is enabled.
+extern crate digits;
+use digits::prelude::*;
+use std::time::Instant;
+
+// A pseudo-random value of the given length in base 10.
+fn operand(base10: &BaseCustom<char>, places: usize, seed: u64) -> Digits {
+  let mut state = seed;
+  let text: String = (0..places).map(|_| {
+    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    char::from(b'0' + ((state >> 33) % 10) as u8)
+  }).collect();
+  Digits::new(base10.clone(), text)
+}
+
+fn bench(name: &str, a: &Digits, b: &Digits, threshold: usize) -> Digits {
+  let start = Instant::now();
+  let product = a.mul_with_threshold(b, threshold);
+  println!("{:<34} {:>10.1} ms", name, start.elapsed().as_secs_f64() * 1e3);
+  product
+}
+
+fn main() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let karatsuba = Digits::karatsuba_threshold();
+
+  for &places in &[1_000, 10_000, 100_000] {
+    let a = operand(&base10, places, 1);
+    let b = operand(&base10, places, 2);
+
+    let fast = bench(&format!("karatsuba  {:>6} x {:>6} places", places, places), &a, &b, karatsuba);
+
+    // Schoolbook at 100k places takes around ten billion steps; skip it unless asked.
+    if places < 100_000 || std::env::args().any(|arg| arg == "--all") {
+      let slow = bench(&format!("schoolbook {:>6} x {:>6} places", places, places), &a, &b, usize::MAX);
+      assert!(fast.eq_exact(&slow));
+    }
+  }
+
+  let a = operand(&base10, 4_000, 3);
+  for &threshold in &[8, 16, 32, 64, 128] {
+    bench(&format!("threshold {:>3}, 4000 x 4000 places", threshold), &a, &a, threshold);
+  }
+}
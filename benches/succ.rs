@@ -0,0 +1,40 @@
+// Throughput of stepping Digits up and down by one.
+//
+// Run with `cargo bench --bench succ`.
+extern crate digits;
+use digits::prelude::*;
+use std::time::Instant;
+
+const STEPS: u32 = 10_000_000;
+
+fn bench<F: FnMut()>(name: &str, steps: u32, mut step: F) {
+  let start = Instant::now();
+  for _ in 0..steps { step(); }
+  let secs = start.elapsed().as_secs_f64();
+  println!("{:<26} {:>8.1} ms {:>8.1} M steps/s", name, secs * 1e3, f64::from(steps) / secs / 1e6);
+}
+
+fn main() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let base62 = BaseCustom::<char>::new(
+    "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().collect()
+  );
+
+  let mut num = Digits::new_zero(base10.clone());
+  bench("succ_in_place base 10", STEPS, || { num.succ_in_place(); });
+  assert_eq!(num.to_s(), STEPS.to_string());
+
+  let mut num = Digits::new_zero(base62);
+  bench("succ_in_place base 62", STEPS, || { num.succ_in_place(); });
+
+  let mut num = Digits::new(base10.clone(), "0".repeat(16));
+  bench("succ_in_place padded", STEPS, || { num.succ_in_place(); });
+  assert_eq!(num.length(), 16);
+
+  let mut num = Digits::new(base10.clone(), "9".repeat(8));
+  bench("pred_till_zero_in_place", STEPS, || { num.pred_till_zero_in_place(); });
+  assert_eq!(num.to_s(), "89999999");
+
+  let mut num = Digits::new_zero(base10);
+  bench("succ (cloning)", STEPS / 10, || { num.succ(); });
+}
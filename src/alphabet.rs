@@ -0,0 +1,61 @@
+//! Checks for character mappings and values that come from outside the program, such
+//! as command line arguments, checkpoint files or serialized data.
+//!
+//! `BaseCustom` itself accepts any characters, so a repeated character or an unknown
+//! one in a value only shows up later as a wrong result.  These reject them up front.
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use {BaseCustom, Digits};
+
+/// Creates a character mapping from a string of characters ordered from zero.
+///
+/// Returns an `Err(&'static str)` Result if there are fewer than 2 or more than 255
+/// characters, or if any character is repeated.
+///
+/// # Example
+///
+/// ```
+/// use digits::alphabet;
+///
+/// assert_eq!(alphabet::mapping("01").unwrap().base, 2);
+/// assert!(alphabet::mapping("0").is_err());
+/// assert!(alphabet::mapping("010").is_err());
+/// ```
+pub fn mapping(chars: &str) -> Result<BaseCustom<char>, &'static str> {
+  let count = chars.chars().count();
+  if count < 2 { return Err("Too few characters for an alphabet!"); }
+  if count > 255 { return Err("Too many characters for an alphabet!"); }
+  let mut seen = HashSet::with_capacity(count);
+  if !chars.chars().all(|c| seen.insert(c)) {
+    return Err("Alphabet characters must be unique!");
+  }
+  Ok(BaseCustom::<char>::new(chars.chars().collect()))
+}
+
+/// Reads a value written in the characters of the given mapping, zero padding included.
+///
+/// Returns an `Err(&'static str)` Result if the text is empty or has a character
+/// outside of the mapping.
+///
+/// # Example
+///
+/// ```
+/// use digits::alphabet;
+///
+/// let letters = alphabet::mapping("abc").unwrap();
+///
+/// assert_eq!(alphabet::value(&letters, "acb").unwrap().to_s(), "acb");
+/// assert!(alphabet::value(&letters, "abd").is_err());
+/// assert!(alphabet::value(&letters, "").is_err());
+/// ```
+pub fn value(mapping: &BaseCustom<char>, text: &str) -> Result<Digits, &'static str> {
+  if text.is_empty() { return Err("A value needs at least one character!"); }
+  let positions: HashMap<char, u64> = (0..mapping.base as usize)
+    .filter_map(|i| mapping.char(i).map(|c| (c, i as u64)))
+    .collect();
+  let places = text.chars()
+    .map(|c| positions.get(&c).cloned())
+    .collect::<Option<Vec<u64>>>()
+    .ok_or("Value has characters outside of its alphabet!")?;
+  Ok(Digits::from_places(Arc::new(mapping.clone()), &places))
+}
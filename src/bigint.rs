@@ -0,0 +1,77 @@
+//! Conversions between `Digits` and `num_bigint`'s `BigUint` and `BigInt`.
+//!
+//! _Only available with the `num-bigint` feature enabled._
+//!
+//! With this feature `mul`, `pow` and conversion between character mappings are
+//! also carried out by `BigUint`.
+//!
+//! # Example
+//!
+//! ```
+//! extern crate digits;
+//! extern crate num_bigint;
+//! use digits::prelude::*;
+//! use num_bigint::BigUint;
+//!
+//! # fn main() {
+//! let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+//! let big = BigUint::from(48879u32);
+//!
+//! let num = Digits::from((base16, big.clone()));
+//! assert_eq!(num.to_s(), "BEEF");
+//! assert_eq!(BigUint::from(&num), big);
+//! # }
+//! ```
+use std::convert::TryFrom;
+use std::sync::Arc;
+use num_bigint::{BigUint,BigInt,Sign};
+use super::{BaseCustom,Digits};
+
+impl<'a> From<&'a Digits> for BigUint {
+  fn from(d: &'a Digits) -> BigUint {
+    let places: Vec<u8> = d.as_mapping_vec().iter().map(|&x| x as u8).collect();
+    BigUint::from_radix_be(&places, d.mapping.base as u32).unwrap()
+  }
+}
+
+impl<'a> From<&'a Digits> for BigInt {
+  fn from(d: &'a Digits) -> BigInt {
+    BigInt::from(BigUint::from(d))
+  }
+}
+
+impl From<(BaseCustom<char>, BigUint)> for Digits {
+  fn from(d: (BaseCustom<char>, BigUint)) -> Digits {
+    from_biguint(Arc::new(d.0), &d.1)
+  }
+}
+
+/// Returns an `Err(&'static str)` Result for negative values.
+impl TryFrom<(BaseCustom<char>, BigInt)> for Digits {
+  type Error = &'static str;
+  fn try_from(d: (BaseCustom<char>, BigInt)) -> Result<Digits, &'static str> {
+    match d.1.into_parts() {
+      (Sign::Minus, _) => Err("Digits can not hold a negative value!"),
+      (_, magnitude) => Ok(Digits::from((d.0, magnitude))),
+    }
+  }
+}
+
+fn from_biguint(mapping: Arc<BaseCustom<char>>, big: &BigUint) -> Digits {
+  let places: Vec<u64> = big.to_radix_be(mapping.base as u32).iter().map(|&x| u64::from(x)).collect();
+  Digits::from_places(mapping, &places)
+}
+
+pub(crate) fn mul(a: &Digits, b: &Digits) -> Digits {
+  from_biguint(a.mapping.clone(), &(BigUint::from(a) * BigUint::from(b)))
+}
+
+// Gives `None` when the power is beyond a `u32`.
+pub(crate) fn pow(a: &Digits, pwr: &Digits) -> Option<Digits> {
+  let exp = u32::try_from(&BigUint::from(pwr)).ok()?;
+  Some(from_biguint(a.mapping.clone(), &BigUint::from(a).pow(exp)))
+}
+
+pub(crate) fn convert(mapping: BaseCustom<char>, source: &Digits) -> Digits {
+  Digits::from((mapping, BigUint::from(source)))
+}
@@ -0,0 +1,259 @@
+// Command line access to Digits enumeration and arithmetic.
+//
+// Every command writes to stdout so it can be piped into other tools.
+extern crate digits;
+use digits::prelude::*;
+use digits::alphabet;
+use digits::mask::Mask;
+use digits::radices::decimal_base;
+use digits::range::DigitsRange;
+use digits::writer::CandidateWriter;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process;
+
+const USAGE: &str = "\
+usage: digits <command> [options]
+
+commands:
+  gen      --charset <chars> (--start <value> --end <value> | --length <n>)
+           [--prefix <text>] [--suffix <text>] [--separator <text>]
+  count    --charset <chars> (--start <value> --end <value> | --length <n>)
+  count    --mask <mask>
+  convert  --from <chars> --to <chars> [<value>...]
+  calc     --charset <chars> <a> <add|sub|mul|div|rem|pow> <b>
+  mask     <mask> [--prefix <text>] [--suffix <text>] [--separator <text>]
+
+Options a command does not list are rejected.  The charset defaults to 0123456789.  When gen is given an end without a start it
+counts up from the first character padded to the width of the end.  convert reads
+values from stdin, one per line, when none are given.  The separator defaults to a
+newline and understands the escapes \\n, \\t, \\0 and \\\\.
+
+Mask placeholders: ?l lower, ?u upper, ?d digits, ?h/?H hex, ?s symbols, ?a all, ?? for ?.";
+
+enum Failure {
+  Usage(String),
+  Io(io::Error),
+}
+
+impl From<io::Error> for Failure {
+  fn from(err: io::Error) -> Failure { Failure::Io(err) }
+}
+
+impl<'a> From<&'a str> for Failure {
+  fn from(msg: &'a str) -> Failure { Failure::Usage(msg.to_string()) }
+}
+
+struct Args {
+  options: HashMap<String, String>,
+  values: Vec<String>,
+  help: bool,
+}
+
+impl Args {
+  fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Args, Failure> {
+    let mut options = HashMap::new();
+    let mut values = vec![];
+    let mut help = false;
+    while let Some(arg) = args.next() {
+      if arg == "-h" || arg == "--help" {
+        help = true;
+      } else if let Some(name) = arg.strip_prefix("--") {
+        let value = args.next().ok_or_else(|| Failure::Usage(format!("--{} needs a value", name)))?;
+        options.insert(name.to_string(), value);
+      } else {
+        values.push(arg);
+      }
+    }
+    Ok(Args { options, values, help })
+  }
+
+  // Rejects options outside `accepted` and, unless `values` allows them, any values.
+  fn accept(&self, command: &str, accepted: &[&str], values: bool) -> Result<(), Failure> {
+    let mut names: Vec<&str> = self.options.keys().map(|s| s.as_str()).collect();
+    names.sort_unstable();
+    if let Some(name) = names.into_iter().find(|name| !accepted.contains(name)) {
+      return Err(Failure::Usage(format!("{} does not take --{}", command, name)));
+    }
+    if let (false, Some(value)) = (values, self.values.first()) {
+      return Err(Failure::Usage(format!("{} does not take the argument {:?}", command, value)));
+    }
+    Ok(())
+  }
+
+  fn get(&self, name: &str) -> Option<&str> {
+    self.options.get(name).map(|s| s.as_str())
+  }
+
+  fn require(&self, name: &str) -> Result<&str, Failure> {
+    self.get(name).ok_or_else(|| Failure::Usage(format!("missing --{}", name)))
+  }
+
+  fn charset(&self, name: &str) -> Result<BaseCustom<char>, Failure> {
+    alphabet::mapping(self.get(name).unwrap_or("0123456789")).map_err(Failure::from)
+  }
+
+  fn writer<W: Write>(&self, out: W) -> CandidateWriter<W> {
+    let text = |name| unescape(self.get(name).unwrap_or(""));
+    CandidateWriter::new(out)
+      .prefix(&text("prefix"))
+      .suffix(&text("suffix"))
+      .separator(&self.get("separator").map(unescape).unwrap_or_else(|| "\n".to_string()))
+  }
+}
+
+fn unescape(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut chars = text.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' { result.push(c); continue; }
+    match chars.next() {
+      Some('n') => result.push('\n'),
+      Some('t') => result.push('\t'),
+      Some('0') => result.push('\0'),
+      Some('\\') => result.push('\\'),
+      Some(other) => { result.push('\\'); result.push(other); },
+      None => result.push('\\'),
+    }
+  }
+  result
+}
+
+fn range(args: &Args) -> Result<DigitsRange, Failure> {
+  let mapping = args.charset("charset")?;
+  let (start, end) = match (args.get("length"), args.get("start"), args.get("end")) {
+    (Some(length), None, None) => {
+      let length: usize = length.parse().map_err(|_| Failure::from("--length must be a number"))?;
+      if length == 0 { return Err("--length must be at least 1".into()); }
+      let zero = mapping.char(0).unwrap().to_string().repeat(length);
+      let top = mapping.char(mapping.base as usize - 1).unwrap().to_string().repeat(length);
+      (alphabet::value(&mapping, &zero)?, alphabet::value(&mapping, &top)?)
+    },
+    (None, start, Some(end)) => {
+      let end = alphabet::value(&mapping, end)?;
+      let start = match start {
+        Some(start) => alphabet::value(&mapping, start)?,
+        None => alphabet::value(&mapping, &mapping.char(0).unwrap().to_string().repeat(end.length()))?,
+      };
+      (start, end)
+    },
+    _ => return Err("give either --length or --end with an optional --start".into()),
+  };
+  Ok(DigitsRange::new(start, end)?)
+}
+
+fn run(command: &str, args: Args, out: &mut dyn Write) -> Result<(), Failure> {
+  match command {
+    "gen" => {
+      args.accept(command, &["charset", "start", "end", "length", "prefix", "suffix", "separator"], false)?;
+      let mut writer = args.writer(out);
+      writer.write_range(range(&args)?)?;
+      writer.flush()?;
+    },
+    "count" => {
+      let count = match args.get("mask") {
+        Some(mask) => {
+          args.accept(command, &["mask"], false)?;
+          Mask::new(mask)?.keyspace()
+        },
+        None => {
+          args.accept(command, &["charset", "start", "end", "length"], false)?;
+          Digits::from((decimal_base(), range(&args)?.remaining()))
+        },
+      };
+      writeln!(out, "{}", count.to_s())?;
+    },
+    "convert" => {
+      args.accept(command, &["from", "to"], true)?;
+      let from = alphabet::mapping(args.require("from")?)?;
+      let to = alphabet::mapping(args.require("to")?)?;
+      let mut convert = |text: &str| -> Result<(), Failure> {
+        let converted = Digits::from((to.clone(), alphabet::value(&from, text)?));
+        writeln!(out, "{}", converted.to_s())?;
+        Ok(())
+      };
+      if args.values.is_empty() {
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+          let line = line?;
+          if !line.is_empty() { convert(&line)?; }
+        }
+      } else {
+        for text in &args.values { convert(text)?; }
+      }
+    },
+    "calc" => {
+      args.accept(command, &["charset"], true)?;
+      let mapping = args.charset("charset")?;
+      let (a, op, b) = match args.values.as_slice() {
+        [a, op, b] => (alphabet::value(&mapping, a)?, op.as_str(), alphabet::value(&mapping, b)?),
+        _ => return Err("calc takes <a> <operation> <b>".into()),
+      };
+      let result = match op {
+        "add" | "+" => a + b,
+        "sub" | "-" => {
+          if b > a { return Err("the result would be negative".into()); }
+          a - b
+        },
+        "mul" | "x" | "*" => a * b,
+        "div" | "/" if b.is_zero() => return Err("division by zero".into()),
+        "div" | "/" => a / b,
+        "rem" | "%" if b.is_zero() => return Err("division by zero".into()),
+        "rem" | "%" => a % b,
+        "pow" | "^" => a ^ b,
+        _ => return Err(Failure::Usage(format!("unknown operation {:?}", op))),
+      };
+      writeln!(out, "{}", result.to_s())?;
+    },
+    "mask" => {
+      args.accept(command, &["prefix", "suffix", "separator"], true)?;
+      let pattern = match args.values.as_slice() {
+        [pattern] => pattern.clone(),
+        _ => return Err("mask takes a single pattern".into()),
+      };
+      let mut writer = args.writer(out);
+      writer.write_mask(&Mask::new(&pattern)?)?;
+      writer.flush()?;
+    },
+    _ => return Err(Failure::Usage(format!("unknown command {:?}", command))),
+  }
+  Ok(())
+}
+
+fn main() {
+  let mut argv = std::env::args().skip(1);
+  let command = match argv.next() {
+    Some(ref c) if c == "-h" || c == "--help" || c == "help" => {
+      println!("{}", USAGE);
+      return;
+    },
+    Some(c) => c,
+    None => {
+      eprintln!("{}", USAGE);
+      process::exit(2);
+    },
+  };
+
+  let stdout = io::stdout();
+  let mut out = BufWriter::new(stdout.lock());
+  let result = Args::parse(argv).and_then(|args| {
+    if args.help {
+      writeln!(out, "{}", USAGE)?;
+      return Ok(());
+    }
+    run(&command, args, &mut out)
+  });
+  let result = result.and_then(|_| out.flush().map_err(Failure::Io));
+  match result {
+    Ok(()) => {},
+    Err(Failure::Io(ref err)) if err.kind() == io::ErrorKind::BrokenPipe => {},
+    Err(Failure::Io(err)) => {
+      eprintln!("digits: {}", err);
+      process::exit(1);
+    },
+    Err(Failure::Usage(msg)) => {
+      eprintln!("digits: {}\nrun `digits --help` for usage", msg);
+      process::exit(2);
+    },
+  }
+}
@@ -0,0 +1,149 @@
+//! A compact, versioned binary format for `Digits`.
+//!
+//! Each record is laid out as:
+//!
+//! ```text
+//! magic      3 bytes   b"DGT"
+//! version    1 byte    FORMAT_VERSION
+//! alphabet   8 bytes   little endian `fingerprint` of the character mapping
+//! length     varint    number of character positions
+//! places     varint    each character position, most significant first
+//! ```
+//!
+//! Varints are unsigned LEB128, so a position below 128 takes a single byte.  The
+//! alphabet itself is not stored; it must be supplied on load and is checked against
+//! the fingerprint.  Zero padding is preserved.
+use std::io::{self, Read, Write};
+use super::{BaseCustom, Digits};
+
+/// The current version of the binary format written by `Digits::write_to`.
+pub const FORMAT_VERSION: u8 = 1;
+
+const MAGIC: &[u8; 3] = b"DGT";
+
+/// A 64 bit FNV-1a hash identifying a character mapping by its characters and their order.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::binary::fingerprint;
+///
+/// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+/// let letters = BaseCustom::<char>::new("ABCDEFGHIJ".chars().collect());
+///
+/// assert_eq!(fingerprint(&base10), fingerprint(&base10.clone()));
+/// assert_ne!(fingerprint(&base10), fingerprint(&letters));
+/// ```
+pub fn fingerprint(mapping: &BaseCustom<char>) -> u64 {
+  let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+  let mut feed = |byte: u8| {
+    hash ^= u64::from(byte);
+    hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+  };
+  feed(mapping.base as u8);
+  for i in 0..mapping.base as usize {
+    let mut buf = [0; 4];
+    for &byte in mapping.nth(i).unwrap().encode_utf8(&mut buf).as_bytes() {
+      feed(byte);
+    }
+  }
+  hash
+}
+
+fn write_varint<W: Write>(writer: &mut W, mut value: u64) -> io::Result<()> {
+  let mut buf = [0u8; 10];
+  let mut len = 0;
+  loop {
+    let byte = (value & 0x7f) as u8;
+    value >>= 7;
+    if value == 0 {
+      buf[len] = byte;
+      len += 1;
+      break;
+    }
+    buf[len] = byte | 0x80;
+    len += 1;
+  }
+  writer.write_all(&buf[..len])
+}
+
+fn read_varint<R: Read>(reader: &mut R) -> io::Result<u64> {
+  let mut value: u64 = 0;
+  let mut byte = [0u8; 1];
+  for shift in (0..64).step_by(7) {
+    reader.read_exact(&mut byte)?;
+    let low = u64::from(byte[0] & 0x7f);
+    if shift == 63 && low > 1 {
+      return Err(invalid("Varint overflows u64!"));
+    }
+    value |= low << shift;
+    if byte[0] & 0x80 == 0 { return Ok(value); }
+  }
+  Err(invalid("Varint overflows u64!"))
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl Digits {
+  /// Writes this value in the compact binary format described in the `binary` module.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::new(base10.clone(), "0042".to_string());
+  ///
+  /// let mut buffer: Vec<u8> = vec![];
+  /// num.write_to(&mut buffer).unwrap();
+  ///
+  /// let loaded = Digits::read_from(base10, &mut &buffer[..]).unwrap();
+  /// assert!(loaded.eq_exact(&num));
+  /// ```
+  pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+    let places = self.as_mapping_vec();
+    let mut buf: Vec<u8> = Vec::with_capacity(13 + places.len());
+    buf.extend_from_slice(MAGIC);
+    buf.push(FORMAT_VERSION);
+    buf.extend_from_slice(&fingerprint(&self.mapping).to_le_bytes());
+    write_varint(&mut buf, places.len() as u64)?;
+    for place in places {
+      write_varint(&mut buf, place)?;
+    }
+    writer.write_all(&buf)
+  }
+
+  /// Reads a value written by `write_to` using the given character mapping.
+  ///
+  /// Returns an `io::Error` of kind `InvalidData` if the header, version or alphabet
+  /// fingerprint do not match, or if a character position is out of range for the mapping.
+  pub fn read_from<R: Read>(mapping: BaseCustom<char>, reader: &mut R) -> io::Result<Digits> {
+    let mut header = [0u8; 12];
+    reader.read_exact(&mut header)?;
+    if &header[0..3] != MAGIC {
+      return Err(invalid("Not a binary Digits record!"));
+    }
+    if header[3] != FORMAT_VERSION {
+      return Err(invalid("Unsupported binary Digits format version!"));
+    }
+    let mut print = [0u8; 8];
+    print.copy_from_slice(&header[4..12]);
+    if u64::from_le_bytes(print) != fingerprint(&mapping) {
+      return Err(invalid("Alphabet fingerprint does not match!"));
+    }
+
+    let len = read_varint(reader)?;
+    if len == 0 {
+      return Err(invalid("A binary Digits record needs at least one place!"));
+    }
+    let mut places: Vec<u64> = Vec::with_capacity(std::cmp::min(len, 4096) as usize);
+    for _ in 0..len {
+      places.push(read_varint(reader)?);
+    }
+    Digits::new_zero(mapping).new_mapped(&places).map_err(invalid)
+  }
+}
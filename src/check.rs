@@ -0,0 +1,237 @@
+//! Check characters for identifiers in any alphabet.
+//!
+//! A check character is worked out from the mapping indices of a payload and appended
+//! as its least significant place, so most typing mistakes leave an invalid value.
+//!
+//! | Scheme     | Alphabets      | Catches                                          |
+//! |------------|----------------|--------------------------------------------------|
+//! | `LuhnModN` | any size       | single substitutions, most adjacent swaps        |
+//! | `Damm`     | ten characters | single substitutions, all adjacent swaps         |
+//! | `Verhoeff` | ten characters | single substitutions, all adjacent swaps         |
+use range::DigitsRange;
+use Digits;
+
+/// A check character scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckScheme {
+  /// Luhn mod N, the Luhn algorithm generalised to an alphabet of any size.
+  LuhnModN,
+  /// The Damm algorithm.  Needs an alphabet of ten characters.
+  Damm,
+  /// The Verhoeff algorithm.  Needs an alphabet of ten characters.
+  Verhoeff,
+}
+
+const DAMM: [[u8; 10]; 10] = [
+  [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+  [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+  [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+  [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+  [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+  [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+  [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+  [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+  [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+  [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+// Multiplication in the dihedral group D5.
+const VERHOEFF_D: [[u8; 10]; 10] = [
+  [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+  [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+  [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+  [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+  [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+  [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+  [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+  [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+  [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+  [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+// The position dependent permutations.
+const VERHOEFF_P: [[u8; 10]; 8] = [
+  [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+  [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+  [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+  [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+  [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+  [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+  [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+  [7, 0, 4, 6, 9, 1, 3, 5, 8, 2],
+];
+
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+impl CheckScheme {
+  fn supports(self, base: u64) -> Result<(), &'static str> {
+    match self {
+      CheckScheme::LuhnModN => Ok(()),
+      CheckScheme::Damm | CheckScheme::Verhoeff if base == 10 => Ok(()),
+      _ => Err("This check scheme needs an alphabet of ten characters!"),
+    }
+  }
+
+  // The check place for `payload`, most significant place first.
+  fn compute(self, payload: &[u64], base: u64) -> u64 {
+    match self {
+      CheckScheme::LuhnModN => (base - luhn_sum(payload, base, true)) % base,
+      CheckScheme::Damm => payload.iter().fold(0, |interim, &p| DAMM[interim as usize][p as usize] as u64),
+      CheckScheme::Verhoeff => {
+        let c = verhoeff_sum(payload, 1);
+        VERHOEFF_INV[c as usize] as u64
+      },
+    }
+  }
+
+  // Whether the last place of `value` is the check place for the rest.
+  fn verify(self, value: &[u64], base: u64) -> bool {
+    match self {
+      CheckScheme::LuhnModN => luhn_sum(value, base, false) == 0,
+      CheckScheme::Damm => self.compute(value, base) == 0,
+      CheckScheme::Verhoeff => verhoeff_sum(value, 0) == 0,
+    }
+  }
+}
+
+// The Luhn mod N sum of `places` modulo the base, doubling from the least significant
+// place when it is a payload without its check place.
+fn luhn_sum(places: &[u64], base: u64, payload: bool) -> u64 {
+  let mut double = payload;
+  let mut sum = 0;
+  for &place in places.iter().rev() {
+    let addend = if double { place * 2 } else { place };
+    sum = (sum + addend / base + addend % base) % base;
+    double = !double;
+  }
+  sum
+}
+
+fn verhoeff_sum(places: &[u64], offset: usize) -> u8 {
+  places.iter().rev().enumerate().fold(0, |c, (i, &place)| {
+    VERHOEFF_D[c as usize][VERHOEFF_P[(i + offset) % 8][place as usize] as usize]
+  })
+}
+
+impl Digits {
+  /// The check character for this value under the given scheme.  Zero padding counts
+  /// as part of the payload, which only matters for `Verhoeff`.
+  ///
+  /// Returns an `Err(&'static str)` Result if the scheme does not support the
+  /// value's alphabet.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use digits::check::CheckScheme;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let payload = Digits::new(base10, "7992739871".to_string());
+  ///
+  /// assert_eq!(payload.check_char(CheckScheme::LuhnModN), Ok('3'));
+  /// ```
+  pub fn check_char(&self, scheme: CheckScheme) -> Result<char, &'static str> {
+    scheme.supports(self.mapping.base)?;
+    let check = scheme.compute(&self.as_mapping_vec(), self.mapping.base);
+    Ok(self.mapping.char(check as usize).unwrap())
+  }
+
+  /// Whether the last character of this value is a valid check character for the
+  /// characters before it.
+  ///
+  /// Returns an `Err(&'static str)` Result if the scheme does not support the
+  /// value's alphabet or the value is a single character.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use digits::check::CheckScheme;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  ///
+  /// assert_eq!(Digits::new(base10.clone(), "5724".to_string()).has_valid_check(CheckScheme::Damm), Ok(true));
+  /// assert_eq!(Digits::new(base10, "5274".to_string()).has_valid_check(CheckScheme::Damm), Ok(false));
+  /// ```
+  pub fn has_valid_check(&self, scheme: CheckScheme) -> Result<bool, &'static str> {
+    scheme.supports(self.mapping.base)?;
+    if self.left.is_none() { return Err("A checked value needs a payload and a check character!"); }
+    Ok(scheme.verify(&self.as_mapping_vec(), self.mapping.base))
+  }
+
+  /// This value with its check character appended.
+  ///
+  /// Returns an `Err(&'static str)` Result if the scheme does not support the
+  /// value's alphabet.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use digits::check::CheckScheme;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let payload = Digits::new(base10, "236".to_string());
+  ///
+  /// assert_eq!(payload.with_check(CheckScheme::Verhoeff).unwrap().to_s(), "2363");
+  /// ```
+  pub fn with_check(&self, scheme: CheckScheme) -> Result<Digits, &'static str> {
+    scheme.supports(self.mapping.base)?;
+    let mut places = self.as_mapping_vec();
+    let check = scheme.compute(&places, self.mapping.base);
+    places.push(check);
+    Ok(Digits::from_places(self.mapping.clone(), &places))
+  }
+}
+
+/// Enumerates only values carrying a valid check character: every payload of a
+/// `DigitsRange` with its check character appended.  As the check character is fixed
+/// by the payload, this yields exactly the valid values one place longer than the
+/// range's, without generating and discarding the invalid ones.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::check::{CheckScheme, CheckedRange};
+/// use digits::range::DigitsRange;
+///
+/// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+/// let start = Digits::new(base10.clone(), "00".to_string());
+/// let end = Digits::new(base10, "03".to_string());
+/// let range = DigitsRange::new(start, end).unwrap();
+///
+/// let ids: Vec<String> = CheckedRange::new(range, CheckScheme::LuhnModN).unwrap()
+///   .map(|d| d.to_s()).collect();
+/// assert_eq!(ids, vec!["000", "018", "026", "034"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CheckedRange {
+  range: DigitsRange,
+  scheme: CheckScheme,
+}
+
+impl CheckedRange {
+  /// Wraps a range of payloads.
+  ///
+  /// Returns an `Err(&'static str)` Result if the scheme does not support the
+  /// range's alphabet.
+  pub fn new(range: DigitsRange, scheme: CheckScheme) -> Result<CheckedRange, &'static str> {
+    scheme.supports(range.end().mapping.base)?;
+    Ok(CheckedRange { range, scheme })
+  }
+
+  /// The payload the next value will be built from, or `None` once the range is done.
+  pub fn peek(&self) -> Option<&Digits> {
+    self.range.peek()
+  }
+}
+
+impl Iterator for CheckedRange {
+  type Item = Digits;
+
+  fn next(&mut self) -> Option<Digits> {
+    self.range.next().map(|payload| payload.with_check(self.scheme).unwrap())
+  }
+}
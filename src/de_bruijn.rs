@@ -0,0 +1,186 @@
+//! De Bruijn sequences over a custom alphabet.
+//!
+//! A de Bruijn sequence B(k, n) is a cyclic sequence of `k^n` characters from an
+//! alphabet of `k` in which every string of length `n` appears exactly once as a
+//! window.  Typed into a keypad that checks the last `n` keys pressed, the linear form
+//! tries every code with about `n` times fewer presses than typing each in turn.
+//!
+//! The sequence produced is the lexicographically least one.  It is the concatenation,
+//! in order, of the Lyndon words whose length divides `n`, which are generated one at a
+//! time with Duval's algorithm, so streaming it takes memory in proportion to `n` only.
+use std::sync::Arc;
+use radices::decimal_base;
+use {BaseCustom, Digits};
+
+/// The de Bruijn sequence for an alphabet and window length.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::de_bruijn::DeBruijn;
+///
+/// let base2 = BaseCustom::<char>::new("01".chars().collect());
+/// let sequence = DeBruijn::new(base2, 3).unwrap();
+///
+/// assert_eq!(sequence.iter().collect::<String>(), "00010111");
+/// assert_eq!(sequence.linear().collect::<String>(), "0001011100");
+/// assert_eq!(sequence.window_at(3).unwrap().to_s(), "101");
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeBruijn {
+  mapping: Arc<BaseCustom<char>>,
+  window: usize,
+}
+
+impl DeBruijn {
+  /// Creates the sequence of all windows `window` characters long in `mapping`.
+  ///
+  /// Returns an `Err(&'static str)` Result if `window` is zero.
+  pub fn new(mapping: BaseCustom<char>, window: usize) -> Result<DeBruijn, &'static str> {
+    if window == 0 { return Err("A de Bruijn window needs at least one place!"); }
+    Ok(DeBruijn { mapping: Arc::new(mapping), window })
+  }
+
+  /// The length of one cycle, `base^window`, in decimal.  The linear form is
+  /// `window - 1` characters longer.
+  pub fn cycle_length(&self) -> Digits {
+    let mut total = Digits::new_one(decimal_base());
+    let base = Digits::from((decimal_base(), self.mapping.base));
+    for _ in 0..self.window {
+      total.mul_in_place(&base);
+    }
+    total
+  }
+
+  /// Streams one cycle of the sequence.  Read cyclically, every window appears once.
+  pub fn iter(&self) -> DeBruijnIter {
+    DeBruijnIter {
+      mapping: self.mapping.clone(),
+      window: self.window,
+      word: vec![0],
+      pos: 0,
+      wrap: None,
+    }
+  }
+
+  /// Streams the sequence followed by its first `window - 1` characters, so every
+  /// window appears once when it is read from start to end.
+  pub fn linear(&self) -> DeBruijnIter {
+    let mut iter = self.iter();
+    iter.wrap = Some((Vec::with_capacity(self.window - 1), 0));
+    iter
+  }
+
+  /// The window starting at the given index of the linear sequence, as a value
+  /// `window` places long.
+  ///
+  /// This walks the sequence, so it takes time in proportion to the index.
+  ///
+  /// Returns an `Err(&'static str)` Result if no window starts at that index or the
+  /// sequence is too long to index with a `u64`.
+  pub fn window_at(&self, index: u64) -> Result<Digits, &'static str> {
+    if self.cycle().is_none_or(|cycle| index >= cycle) {
+      return Err("No window starts at that index!");
+    }
+    let mut places = self.linear().places();
+    for _ in 0..index {
+      places.next();
+    }
+    let window: Vec<u64> = places.take(self.window).collect();
+    Ok(Digits::from_places(self.mapping.clone(), &window))
+  }
+
+  /// The index in the sequence at which the given window starts.  Shorter values are
+  /// read with zero padding up to the window length.
+  ///
+  /// This walks the sequence, so it takes time in proportion to the index.
+  ///
+  /// Returns an `Err(&'static str)` Result if the window uses another mapping, is
+  /// longer than the window length, or the sequence is too long to index with a `u64`.
+  pub fn index_of(&self, window: &Digits) -> Result<u64, &'static str> {
+    if window.mapping != self.mapping { return Err("Incompatible numeric base mappings!"); }
+    let cycle = self.cycle().ok_or("The sequence is too long to index!")?;
+    let places = window.as_mapping_vec();
+    let start = places.iter().position(|&x| x != 0).unwrap_or(places.len());
+    if places.len() - start > self.window { return Err("Value is longer than the window!"); }
+
+    // Compare windows as numbers, rolling each new place in and the oldest out.
+    let base = u128::from(self.mapping.base);
+    let wanted = places[start..].iter().fold(0, |acc, &p| acc * base + u128::from(p));
+    let mut current: u128 = 0;
+    for (i, place) in self.linear().places().enumerate() {
+      current = (current * base + u128::from(place)) % u128::from(cycle);
+      if i + 1 >= self.window && current == wanted {
+        return Ok((i + 1 - self.window) as u64);
+      }
+    }
+    unreachable!("every window appears in a de Bruijn sequence")
+  }
+
+  fn cycle(&self) -> Option<u64> {
+    (0..self.window).try_fold(1u64, |acc, _| acc.checked_mul(self.mapping.base))
+  }
+}
+
+/// Streams the characters of a de Bruijn sequence, created by `DeBruijn::iter` or
+/// `DeBruijn::linear`.
+#[derive(Clone, Debug)]
+pub struct DeBruijnIter {
+  mapping: Arc<BaseCustom<char>>,
+  window: usize,
+  // The current Lyndon word, empty once they are all done.
+  word: Vec<u64>,
+  pos: usize,
+  // For the linear form: the leading places seen so far and how many have been repeated.
+  wrap: Option<(Vec<u64>, usize)>,
+}
+
+impl DeBruijnIter {
+  // The same stream as mapping positions rather than characters.
+  fn places(mut self) -> impl Iterator<Item = u64> {
+    std::iter::from_fn(move || self.next_place())
+  }
+
+  fn next_place(&mut self) -> Option<u64> {
+    loop {
+      if self.word.is_empty() {
+        let (head, repeated) = self.wrap.as_mut()?;
+        let place = *head.get(*repeated)?;
+        *repeated += 1;
+        return Some(place);
+      }
+      if self.window.is_multiple_of(self.word.len()) && self.pos < self.word.len() {
+        let place = self.word[self.pos];
+        self.pos += 1;
+        if let Some((ref mut head, _)) = self.wrap {
+          if head.len() < self.window - 1 { head.push(place); }
+        }
+        return Some(place);
+      }
+      self.next_word();
+    }
+  }
+
+  // Duval's step to the next Lyndon word of at most `window` places.
+  fn next_word(&mut self) {
+    let period = self.word.len();
+    while self.word.len() < self.window {
+      let place = self.word[self.word.len() - period];
+      self.word.push(place);
+    }
+    while self.word.last() == Some(&(self.mapping.base - 1)) {
+      self.word.pop();
+    }
+    if let Some(last) = self.word.last_mut() { *last += 1; }
+    self.pos = 0;
+  }
+}
+
+impl Iterator for DeBruijnIter {
+  type Item = char;
+
+  fn next(&mut self) -> Option<char> {
+    self.next_place().map(|place| self.mapping.char(place as usize).unwrap())
+  }
+}
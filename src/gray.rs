@@ -0,0 +1,129 @@
+//! Reflected Gray code ordering for any base.
+//!
+//! In the reflected n-ary Gray code each place counts up, then back down, and then up
+//! again as the places above it change, so consecutive values differ in exactly one
+//! place and only by one step of the alphabet.  With a base of two it is the usual
+//! binary reflected Gray code.
+//!
+//! A Gray value is worked out from the most significant place down.  Every place is
+//! mirrored (`base - 1 - place`) when an odd number of the Gray places above it are
+//! odd.  Leading zeros are never mirrored, so zero padding does not change a value.
+use std::sync::Arc;
+use {BaseCustom, Digits};
+
+fn to_gray(places: &mut [u64], base: u64) {
+  let mut mirrored = false;
+  for place in places.iter_mut() {
+    if mirrored { *place = base - 1 - *place; }
+    if *place % 2 == 1 { mirrored = !mirrored; }
+  }
+}
+
+fn from_gray(places: &mut [u64], base: u64) {
+  let mut mirrored = false;
+  for place in places.iter_mut() {
+    let odd = *place % 2 == 1;
+    if mirrored { *place = base - 1 - *place; }
+    if odd { mirrored = !mirrored; }
+  }
+}
+
+impl Digits {
+  /// The Gray code of this value, read as an index into the Gray code ordering.
+  /// Zero padding is kept.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base3 = BaseCustom::<char>::new("012".chars().collect());
+  /// let index = Digits::new(base3, "010".to_string());
+  ///
+  /// assert_eq!(index.to_gray().to_s(), "012");
+  /// assert_eq!(index.to_gray().from_gray().to_s(), "010");
+  /// ```
+  pub fn to_gray(&self) -> Digits {
+    let mut places = self.as_mapping_vec();
+    to_gray(&mut places, self.mapping.base);
+    Digits::from_places(self.mapping.clone(), &places)
+  }
+
+  /// The index of this Gray code value, undoing `to_gray`.  Zero padding is kept.
+  pub fn from_gray(&self) -> Digits {
+    let mut places = self.as_mapping_vec();
+    from_gray(&mut places, self.mapping.base);
+    Digits::from_places(self.mapping.clone(), &places)
+  }
+}
+
+/// Iterates over every value of a fixed width in reflected Gray code order, so that
+/// each value differs from the one before in exactly one place.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::gray::GrayIter;
+///
+/// let base3 = BaseCustom::<char>::new("abc".chars().collect());
+/// let order: Vec<String> = GrayIter::new(base3, 2).unwrap().map(|d| d.to_s()).collect();
+///
+/// assert_eq!(order, vec!["aa", "ab", "ac", "bc", "bb", "ba", "ca", "cb", "cc"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GrayIter {
+  mapping: Arc<BaseCustom<char>>,
+  // The index of the next value, most significant place first.
+  next: Option<Vec<u64>>,
+}
+
+impl GrayIter {
+  /// Creates an iterator over all values `width` places long in `mapping`.
+  ///
+  /// Returns an `Err(&'static str)` Result if `width` is zero.
+  pub fn new(mapping: BaseCustom<char>, width: usize) -> Result<GrayIter, &'static str> {
+    if width == 0 { return Err("Gray code enumeration needs at least one place!"); }
+    Ok(GrayIter { mapping: Arc::new(mapping), next: Some(vec![0; width]) })
+  }
+
+  /// Creates an iterator over the values `width` places long that continues from the
+  /// given index in the Gray code ordering, as returned by `position`.
+  ///
+  /// Returns an `Err(&'static str)` Result if `width` is zero or the index does not
+  /// fit in `width` places.
+  pub fn from_index(index: &Digits, width: usize) -> Result<GrayIter, &'static str> {
+    if width == 0 { return Err("Gray code enumeration needs at least one place!"); }
+    let all = index.as_mapping_vec();
+    let start = all.iter().position(|&x| x != 0).unwrap_or(all.len());
+    if all.len() - start > width { return Err("Index does not fit in the given width!"); }
+    let mut places = vec![0; width - (all.len() - start)];
+    places.extend_from_slice(&all[start..]);
+    Ok(GrayIter { mapping: index.mapping.clone(), next: Some(places) })
+  }
+
+  /// The index of the value `next` will yield, or `None` once every value is done.
+  pub fn position(&self) -> Option<Digits> {
+    self.next.as_ref().map(|index| Digits::from_places(self.mapping.clone(), index))
+  }
+}
+
+impl Iterator for GrayIter {
+  type Item = Digits;
+
+  fn next(&mut self) -> Option<Digits> {
+    let index = self.next.take()?;
+    let mut value = index.clone();
+    to_gray(&mut value, self.mapping.base);
+    let mut following = index;
+    for i in (0..following.len()).rev() {
+      if following[i] + 1 < self.mapping.base {
+        following[i] += 1;
+        self.next = Some(following);
+        break;
+      }
+      following[i] = 0;
+    }
+    Some(Digits::from_places(self.mapping.clone(), &value))
+  }
+}
@@ -32,6 +32,7 @@ pub(crate) trait CappedAdd<T> where T: Sized + PartialEq {
 }
 
 impl CappedAdd<u64> for SignNum<u64> {
+  #[allow(clippy::nonminimal_bool)]
   fn capped_add(&self, other: Self, cap: (u64, u64)) -> CarryResult<u64> {
     if !(cap.1 == 0) {
       assert!(cap.0 == 0 && cap.1 > 0);
@@ -60,6 +61,7 @@ impl CappedAdd<u64> for u64 {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn it_works() {
   let nine: SignNum<u64> = SignNum {
     num: 9,
@@ -1,2 +1,4 @@
 pub(crate) mod carry_add;
+pub(crate) mod places;
+pub(crate) mod radix;
 pub(crate) mod step_map;
@@ -0,0 +1,251 @@
+// Arithmetic on positional values ordered most significant first,
+// as with `Digits::as_mapping_vec`.
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use internal::carry_add::CappedAdd;
+
+pub(crate) fn trimmed(places: &[u64]) -> &[u64] {
+  let start = places.iter().position(|&x| x != 0).unwrap_or(places.len());
+  &places[start..]
+}
+
+pub(crate) fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+  let (a, b) = (trimmed(a), trimmed(b));
+  a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+// Adds `a` and `b` keeping the length of the longer one, plus a place for any carry.
+pub(crate) fn add(a: &[u64], b: &[u64], base: u64) -> Vec<u64> {
+  let mut result: Vec<u64> = Vec::with_capacity(std::cmp::max(a.len(), b.len()) + 1);
+  let mut carry: u64 = 0;
+  let mut lhs = a.iter().rev();
+  let mut rhs = b.iter().rev();
+  loop {
+    let (x, y) = (lhs.next(), rhs.next());
+    if x.is_none() && y.is_none() { break; }
+    let cr = carry.capped_add(x.unwrap_or(&0) + y.unwrap_or(&0), (0, base));
+    result.push(cr.sign_num.num);
+    carry = cr.carry.map_or(0, |c| c.num);
+  }
+  if carry != 0 { result.push(carry); }
+  result.reverse();
+  result
+}
+
+// Operands with fewer places than this are multiplied by schoolbook.
+static KARATSUBA_THRESHOLD: AtomicUsize = AtomicUsize::new(32);
+
+pub(crate) fn karatsuba_threshold() -> usize {
+  KARATSUBA_THRESHOLD.load(AtomicOrdering::Relaxed)
+}
+
+pub(crate) fn set_karatsuba_threshold(places: usize) {
+  KARATSUBA_THRESHOLD.store(places, AtomicOrdering::Relaxed);
+}
+
+// Multiplies by Karatsuba once both operands reach the threshold and by schoolbook
+// below it.  The product has no zero padding.
+pub(crate) fn mul(a: &[u64], b: &[u64], base: u64) -> Vec<u64> {
+  mul_with_threshold(a, b, base, karatsuba_threshold())
+}
+
+pub(crate) fn mul_with_threshold(a: &[u64], b: &[u64], base: u64, threshold: usize) -> Vec<u64> {
+  let (a, b) = (trimmed(a), trimmed(b));
+  if a.is_empty() || b.is_empty() { return vec![0]; }
+  // The recursion works least significant place first so splits line up on the right.
+  let a: Vec<u64> = a.iter().rev().cloned().collect();
+  let b: Vec<u64> = b.iter().rev().cloned().collect();
+  let mut product = karatsuba(&a, &b, base, std::cmp::max(threshold, 2));
+  while product.len() > 1 && product.last() == Some(&0) { product.pop(); }
+  product.reverse();
+  product
+}
+
+// Schoolbook multiplication.  The product has no zero padding.
+#[cfg(test)]
+pub(crate) fn schoolbook(a: &[u64], b: &[u64], base: u64) -> Vec<u64> {
+  mul_with_threshold(a, b, base, usize::MAX)
+}
+
+// Karatsuba on least significant first places: with `a = a1·B^m + a0` and likewise
+// for `b`, the product is `z2·B^2m + z1·B^m + z0` where `z0 = a0·b0`, `z2 = a1·b1` and
+// `z1 = (a0 + a1)(b0 + b1) - z0 - z2`, three half size products instead of four.
+// Results may carry high zero places.
+fn karatsuba(a: &[u64], b: &[u64], base: u64, threshold: usize) -> Vec<u64> {
+  if std::cmp::min(a.len(), b.len()) < threshold {
+    return schoolbook_le(a, b, base);
+  }
+  let m = std::cmp::max(a.len(), b.len()) / 2;
+  let (a0, a1) = a.split_at(std::cmp::min(m, a.len()));
+  let (b0, b1) = b.split_at(std::cmp::min(m, b.len()));
+
+  let z0 = karatsuba(a0, b0, base, threshold);
+  let z2 = karatsuba(a1, b1, base, threshold);
+  let mut z1 = karatsuba(&add_le(a0, a1, base), &add_le(b0, b1, base), base, threshold);
+  sub_le(&mut z1, &z0, base);
+  sub_le(&mut z1, &z2, base);
+
+  let mut result = vec![0; a.len() + b.len()];
+  add_at(&mut result, &z0, 0, base);
+  add_at(&mut result, &z1, m, base);
+  add_at(&mut result, &z2, 2 * m, base);
+  result
+}
+
+fn schoolbook_le(a: &[u64], b: &[u64], base: u64) -> Vec<u64> {
+  let mut result = vec![0u64; a.len() + b.len()];
+  for (i, &x) in a.iter().enumerate() {
+    if x == 0 { continue; }
+    let mut carry = 0;
+    for (j, &y) in b.iter().enumerate() {
+      let acc = result[i + j] + x * y + carry;
+      result[i + j] = acc % base;
+      carry = acc / base;
+    }
+    result[i + b.len()] = carry;
+  }
+  result
+}
+
+fn add_le(a: &[u64], b: &[u64], base: u64) -> Vec<u64> {
+  let mut result = a.to_vec();
+  add_at(&mut result, b, 0, base);
+  result
+}
+
+// `target += value · B^offset`, growing `target` as the sum needs.
+fn add_at(target: &mut Vec<u64>, value: &[u64], offset: usize, base: u64) {
+  let mut carry = 0;
+  let mut i = offset;
+  for &place in value {
+    if i == target.len() { target.push(0); }
+    let sum = target[i] + place + carry;
+    target[i] = sum % base;
+    carry = sum / base;
+    i += 1;
+  }
+  while carry != 0 {
+    if i == target.len() { target.push(0); }
+    let sum = target[i] + carry;
+    target[i] = sum % base;
+    carry = sum / base;
+    i += 1;
+  }
+}
+
+// `target -= value` where the difference is known not to be negative.
+fn sub_le(target: &mut [u64], value: &[u64], base: u64) {
+  let mut borrow = 0;
+  for (i, place) in target.iter_mut().enumerate() {
+    let subtrahend = value.get(i).cloned().unwrap_or(0) + borrow;
+    if subtrahend == 0 && i >= value.len() { return; }
+    if *place >= subtrahend {
+      *place -= subtrahend;
+      borrow = 0;
+    } else {
+      *place = *place + base - subtrahend;
+      borrow = 1;
+    }
+  }
+}
+
+// Subtracts `b` from `a` keeping the length of `a`.  Returns `None` if `b` is larger.
+pub(crate) fn sub(a: &[u64], b: &[u64], base: u64) -> Option<Vec<u64>> {
+  if cmp(a, b) == Ordering::Less { return None; }
+  let mut result = a.to_vec();
+  let mut borrow = 0;
+  let mut rhs = b.iter().rev();
+  for place in result.iter_mut().rev() {
+    let subtrahend = rhs.next().cloned().unwrap_or(0) + borrow;
+    if *place >= subtrahend {
+      *place -= subtrahend;
+      borrow = 0;
+    } else {
+      *place = *place + base - subtrahend;
+      borrow = 1;
+    }
+  }
+  Some(result)
+}
+
+// Long division of `a` by `b`.  Both quotient and remainder are returned without
+// zero padding.  Returns `None` when dividing by zero.
+pub(crate) fn div_rem(a: &[u64], b: &[u64], base: u64) -> Option<(Vec<u64>, Vec<u64>)> {
+  let divisor = trimmed(b);
+  if divisor.is_empty() { return None; }
+  let mut quotient: Vec<u64> = Vec::with_capacity(a.len());
+  let mut remainder: Vec<u64> = vec![];
+  for &place in trimmed(a) {
+    remainder.push(place);
+    let mut q = 0;
+    while cmp(&remainder, divisor) != Ordering::Less {
+      remainder = sub(&remainder, divisor, base).unwrap();
+      q += 1;
+    }
+    quotient.push(q);
+  }
+  let finish = |v: &[u64]| {
+    let t = trimmed(v);
+    if t.is_empty() { vec![0] } else { t.to_vec() }
+  };
+  Some((finish(&quotient), finish(&remainder)))
+}
+
+#[test]
+fn it_adds_with_carry() {
+  assert_eq!(add(&[0,0,9], &[1], 10), vec![0,1,0]);
+  assert_eq!(add(&[9,9], &[0,0,1], 10), vec![1,0,0]);
+  assert_eq!(add(&[15], &[15], 16), vec![1,14]);
+}
+
+#[test]
+fn it_multiplies() {
+  assert_eq!(mul(&[4,8,2], &[3,8], 10), vec![1,8,3,1,6]);
+  assert_eq!(mul(&[15,15], &[15,15], 16), vec![15,14,0,1]);
+  assert_eq!(mul(&[0,0], &[7], 10), vec![0]);
+}
+
+#[test]
+fn it_subtracts_with_borrow() {
+  assert_eq!(sub(&[1,0,0], &[1], 10), Some(vec![0,9,9]));
+  assert_eq!(sub(&[0,0,4,2], &[4,2], 10), Some(vec![0,0,0,0]));
+  assert_eq!(sub(&[4,2], &[0,4,3], 10), None);
+}
+
+#[test]
+fn it_divides_with_remainder() {
+  assert_eq!(div_rem(&[1,0,0], &[7], 10), Some((vec![1,4], vec![2])));
+  assert_eq!(div_rem(&[0,0,6], &[1,2], 10), Some((vec![0], vec![6])));
+  assert_eq!(div_rem(&[15,15], &[1,0], 16), Some((vec![15], vec![15])));
+  assert_eq!(div_rem(&[1], &[0,0], 10), None);
+}
+
+#[test]
+fn karatsuba_matches_schoolbook() {
+  let mut state: u64 = 1;
+  let mut next = |n: u64| {
+    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (state >> 33) % n
+  };
+  for &base in &[2, 10, 16, 255] {
+    for &(x, y) in &[(1, 1), (5, 9), (40, 40), (63, 64), (100, 7), (257, 130), (500, 499)] {
+      let a: Vec<u64> = (0..x).map(|_| next(base)).collect();
+      let b: Vec<u64> = (0..y).map(|_| next(base)).collect();
+      let expected = schoolbook(&a, &b, base);
+      for &threshold in &[2, 3, 8, 32] {
+        assert_eq!(mul_with_threshold(&a, &b, base, threshold), expected, "base {} {}x{} at {}", base, x, y, threshold);
+      }
+    }
+  }
+}
+
+#[test]
+fn karatsuba_handles_all_top_places() {
+  let nines = vec![9; 300];
+  let mut expected = vec![9; 299];
+  expected.push(8);
+  expected.extend(vec![0; 299]);
+  expected.push(1);
+  assert_eq!(mul_with_threshold(&nines, &nines, 10, 2), expected);
+  assert_eq!(mul_with_threshold(&[0, 0, 0], &nines, 10, 2), vec![0]);
+}
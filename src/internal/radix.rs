@@ -0,0 +1,34 @@
+// Converts positional digit values from one numeric base to another.
+//
+// Places are ordered most significant first, as with `Digits::as_mapping_vec`,
+// and the result carries no zero padding.  Zero is returned as `vec![0]`.
+pub(crate) fn convert(places: &[u64], from: u64, to: u64) -> Vec<u64> {
+  let mut source: Vec<u64> = places.iter().cloned().skip_while(|&x| x == 0).collect();
+  if source.is_empty() { return vec![0]; }
+  if from == to { return source; }
+
+  let mut result: Vec<u64> = vec![];
+  while !source.is_empty() {
+    let mut remainder = 0;
+    let mut quotient: Vec<u64> = Vec::with_capacity(source.len());
+    for &place in source.iter() {
+      let acc = remainder * from + place;
+      let q = acc / to;
+      remainder = acc % to;
+      if !quotient.is_empty() || q != 0 { quotient.push(q); }
+    }
+    result.push(remainder);
+    source = quotient;
+  }
+  result.reverse();
+  result
+}
+
+#[test]
+fn it_converts_between_bases() {
+  assert_eq!(convert(&[2,5,5], 10, 16), vec![15,15]);
+  assert_eq!(convert(&[15,15], 16, 2), vec![1,1,1,1,1,1,1,1]);
+  assert_eq!(convert(&[0,0,1,0], 2, 10), vec![2]);
+  assert_eq!(convert(&[0,0], 10, 3), vec![0]);
+  assert_eq!(convert(&[0,4,2], 10, 10), vec![4,2]);
+}
@@ -23,23 +23,55 @@ extern crate base_custom;
 pub use base_custom::BaseCustom;
 use std::fmt;
 use std::ops::{
-  Add,AddAssign,Mul,MulAssign,BitXor,BitXorAssign
+  Add,AddAssign,Mul,MulAssign,BitXor,BitXorAssign,Sub,Div,Rem,Shl,ShlAssign,Shr,ShrAssign
 };
 use std::cmp::{PartialOrd,Ordering};
+use std::hash::{Hash,Hasher};
+use std::sync::Arc;
 
 extern crate array_tool;
+#[cfg(feature="serde")]
+extern crate serde;
+#[cfg(feature="num-traits")]
+extern crate num_traits;
+#[cfg(feature="num-bigint")]
+extern crate num_bigint;
+#[cfg(feature="rayon")]
+extern crate rayon;
 mod internal;
+pub mod alphabet;
+#[cfg(feature="num-bigint")]
+pub mod bigint;
+pub mod binary;
+pub mod check;
+pub mod de_bruijn;
+pub mod gray;
+pub mod mask;
+#[cfg(feature="num-traits")]
+pub mod numeric;
+#[cfg(feature="rayon")]
+pub mod par_iter;
+pub mod parallel;
+pub mod random;
+pub mod range;
+pub mod rle;
+pub mod selection;
+#[cfg(feature="serde")]
+pub mod serialization;
+pub mod session;
+pub mod shuffle;
+pub mod stats;
+pub mod writer;
 use internal::step_map::StepMap;
-use internal::carry_add::{CappedAdd,SignNum,Sign};
 
 /// This struct acts similar to a full number with a custom numeric character base
 /// which is provided and mapped via a `BaseCustom` instance.
 ///
-/// The underlying implementation for Digits is a linked list where all the methods recurse
-/// as far as need to to implement the operations.
-#[derive(Clone)]
+/// The underlying implementation for Digits is a linked list.  The methods walk the list
+/// with loops rather than recursion so even values millions of characters long stay
+/// within a constant stack depth.  Every position shares the one character mapping.
 pub struct Digits {
-  mapping: BaseCustom<char>,
+  mapping: Arc<BaseCustom<char>>,
   digit: u64,
   left: Option<Box<Digits>>,
 }
@@ -68,54 +100,57 @@ impl Digits {
   ///
   /// _This will panic if numeric bases are not the same._
   pub fn add(&self, other: Self) -> Self {
-    assert!(self.base() == other.base());
-    let mut result: Vec<u64> = vec![];
-
-    let mut carry: u64 = 0;
+    self.with_identities(&other, |lhs, rhs| {
+      assert!(lhs.base() == rhs.base());
+      let places = internal::places::add(&lhs.as_mapping_vec(), &rhs.as_mapping_vec(), lhs.mapping.base);
+      Digits::from_places(lhs.mapping.clone(), &places)
+    })
+  }
 
-    let mut c_self: Option<Box<Digits>> = Some(Box::new(self.clone()));
-    let mut o_self: Option<Box<Digits>> = Some(Box::new(other));
-    
-    let mut remainder: Option<SignNum<u64>>;
-    loop {
-      if carry == 0 && c_self == None && o_self == None { break }
-      let cs = c_self.clone();
-      let os = o_self.clone();
-      result.push(
-        {
-          let cr = carry.capped_add({
-            cs.unwrap_or_else(|| Box::new(self.zero())).digit +
-            os.unwrap_or_else(|| Box::new(self.zero())).digit},
-            (0, self.base() as u64)
-          );
-          remainder = cr.carry;
-          cr.sign_num.num
-        }
-      );
-      let guard = remainder.unwrap_or_else(|| SignNum::new(0));
-      match guard.sign {
-        Sign::Plus => {
-          carry = guard.num;
-        },
-        Sign::Minus => unimplemented!()
-      }
-      c_self = c_self.unwrap_or_else(|| Box::new(self.zero())).left;
-      o_self = o_self.unwrap_or_else(|| Box::new(self.zero())).left;
-    }
-    result.reverse();
-    self.new_mapped(&result).unwrap()
+  /// Adds the given Digits to self in place without cloning.  The zero padding of
+  /// self is kept but not that of other.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  ///
+  /// let mut num = Digits::new(base10.clone(), "0011".to_string());
+  /// let two = Digits::new(base10, "2".to_string());
+  ///
+  /// num.add_in_place(&two).add_in_place(&two);
+  /// assert_eq!(num.to_s(), "0015");
+  /// ```
+  ///
+  /// _This will panic if numeric bases are not the same._
+  pub fn add_in_place(&mut self, other: &Self) -> &mut Self {
+    if let Some(identity) = self.identity_for(other) { *self = identity; }
+    let resolved = other.identity_for(self);
+    let other = resolved.as_ref().unwrap_or(other);
+    assert!(self.base() == other.base());
+    if other.is_end() { return self; }
+    let other_places = other.as_mapping_vec();
+    let places = internal::places::add(
+      &self.as_mapping_vec(), internal::places::trimmed(&other_places), self.mapping.base
+    );
+    let sum = Digits::from_places(self.mapping.clone(), &places);
+    self.assign(sum);
+    self
   }
 
   /// Returns a vector of each characters position mapping
   pub fn as_mapping_vec(&self) -> Vec<u64> {
-    match self.left {
-      Some(ref l) => {
-        let mut result = l.as_mapping_vec();
-        result.extend(vec![self.digit]);
-        result
-      },
-      None => vec![self.digit],
-    }
+    let mut result: Vec<u64> = self.nodes().map(|d| d.digit).collect();
+    result.reverse();
+    result
+  }
+
+  // Takes on the value of the given Digits in place.
+  fn assign(&mut self, mut d: Digits) {
+    self.digit = d.digit;
+    self.left = d.left.take();
   }
 
   /// Make numeric base size publicly available on Digits
@@ -123,6 +158,144 @@ impl Digits {
     self.mapping.base as usize
   }
 
+  // Compares the positional values of two Digits regardless of their character
+  // mappings.  Walks both linked lists together without cloning.
+  fn cmp_digits(&self, other: &Self) -> Ordering {
+    let mut a = Some(self);
+    let mut b = Some(other);
+    let mut result = Ordering::Equal;
+    while let (Some(x), Some(y)) = (a, b) {
+      match x.digit.cmp(&y.digit) {
+        Ordering::Equal => (),
+        change => { result = change; },
+      }
+      a = x.left.as_deref();
+      b = y.left.as_deref();
+    }
+    match (a, b) {
+      (Some(rest), None) if !rest.is_zero() => Ordering::Greater,
+      (None, Some(rest)) if !rest.is_zero() => Ordering::Less,
+      _ => result,
+    }
+  }
+
+  /// Compares the numeric value of two Digits instances even when they use different
+  /// `BaseCustom` mappings.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use std::cmp::Ordering;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  ///
+  /// let twenty = Digits::new(base10, "20".to_string());
+  /// let hex_f = Digits::new(base16, "F".to_string());
+  ///
+  /// assert_eq!(twenty.cmp_value(&hex_f), Ordering::Greater);
+  /// ```
+  pub fn cmp_value(&self, other: &Self) -> Ordering {
+    if self.base() == other.base() { return self.cmp_digits(other); }
+    let a = internal::radix::convert(&self.as_mapping_vec(), self.mapping.base, self.mapping.base);
+    let b = internal::radix::convert(&other.as_mapping_vec(), other.mapping.base, self.mapping.base);
+    internal::places::cmp(&a, &b)
+  }
+
+  // Converts between numeric bases with BigUint.
+  #[cfg(feature="num-bigint")]
+  fn convert_mapping(mapping: BaseCustom<char>, source: Digits) -> Digits {
+    bigint::convert(mapping, &source)
+  }
+
+  // Converts between numeric bases by long division of the positional values.
+  #[cfg(not(feature="num-bigint"))]
+  fn convert_mapping(mapping: BaseCustom<char>, source: Digits) -> Digits {
+    let places = internal::radix::convert(&source.as_mapping_vec(), source.mapping.base, mapping.base);
+    Digits::from_places(Arc::new(mapping), &places)
+  }
+
+  /// The sum of this value's places, each counted as its index in the mapping.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  /// let num = Digits::new(base16, "FF1".to_string());
+  ///
+  /// assert_eq!(num.digit_sum(), 31);
+  /// ```
+  pub fn digit_sum(&self) -> u64 {
+    self.nodes().map(|d| d.digit).sum()
+  }
+
+  /// The digital root in this value's own base: the single place left after summing
+  /// the places over and over, as an index in the mapping.  It is zero only for zero.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  ///
+  /// assert_eq!(Digits::new(base10.clone(), "65536".to_string()).digital_root(), 7);
+  /// assert_eq!(Digits::new(base10, "000".to_string()).digital_root(), 0);
+  /// ```
+  pub fn digital_root(&self) -> u64 {
+    // Every place value is one more than a multiple of `base - 1`, so the value, its
+    // digit sum and its digital root all agree modulo `base - 1`.
+    let modulus = self.mapping.base - 1;
+    let remainder = self.nodes().fold(0, |acc, d| (acc + d.digit) % modulus);
+    if remainder == 0 && !self.is_zero() { modulus } else { remainder }
+  }
+
+  /// Compares two Digits instances structurally, including their character mapping and
+  /// any zero padding.  Where `==` treats "007" and "7" as the same value this does not.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  ///
+  /// let padded = Digits::new(base10.clone(), "007".to_string());
+  /// let seven = Digits::new(base10, "7".to_string());
+  ///
+  /// assert_eq!(padded, seven);
+  /// assert!(!padded.eq_exact(&seven));
+  /// ```
+  pub fn eq_exact(&self, other: &Self) -> bool {
+    if self.mapping != other.mapping { return false; }
+    let mut a = Some(self);
+    let mut b = Some(other);
+    while let (Some(x), Some(y)) = (a, b) {
+      if x.digit != y.digit { return false; }
+      a = x.left.as_deref();
+      b = y.left.as_deref();
+    }
+    a.is_none() && b.is_none()
+  }
+
+  // Builds the linked list from positional values ordered most significant first.
+  // An empty slice gives zero.
+  fn from_places(mapping: Arc<BaseCustom<char>>, places: &[u64]) -> Digits {
+    let mut iter = places.iter();
+    let mut result = Digits { mapping: mapping.clone(), digit: iter.next().cloned().unwrap_or(0), left: None };
+    for &place in iter {
+      let previous = std::mem::replace(
+        &mut result,
+        Digits { mapping: mapping.clone(), digit: place, left: None }
+      );
+      result.left = Some(Box::new(previous));
+    }
+    result
+  }
+
   /// Allows you to generate/encode a Digits from a `u64` or other `Digits` even if they are of a
   /// different numeric base.
   ///
@@ -140,15 +313,28 @@ impl Digits {
   /// ```
   pub fn gen<T>(&self, other: T) -> Self
   where Self: From<(BaseCustom<char>, T)> {
-    Digits::from((self.mapping.clone(), other))
+    Digits::from(((*self.mapping).clone(), other))
   }
 
-  // the way to recurse and process Digits
-  fn head_tail(self) -> (u64, Option<Box<Self>>) {
-    match self.left {
-      Some(bx) => (self.digit, Some(bx)),
-      None => (self.digit, None),
-    }
+  // The `Zero` and `One` of num-traits, and constants built only from them, have no
+  // mapping of their own.  Met with a value that has one, such a constant is converted
+  // into that value's mapping.
+  #[cfg(feature="num-traits")]
+  fn identity_for(&self, other: &Digits) -> Option<Digits> {
+    if !numeric::is_identity(self) || numeric::is_identity(other) { return None; }
+    Some(Digits::convert_mapping((*other.mapping).clone(), self.clone()))
+  }
+  #[cfg(not(feature="num-traits"))]
+  fn identity_for(&self, _other: &Digits) -> Option<Digits> {
+    None
+  }
+
+  // Applies `f` to both operands once any identity among them is in the other's mapping.
+  fn with_identities<R, F>(&self, other: &Digits, f: F) -> R
+  where F: FnOnce(&Digits, &Digits) -> R {
+    let lhs = self.identity_for(other);
+    let rhs = other.identity_for(self);
+    f(lhs.as_ref().unwrap_or(self), rhs.as_ref().unwrap_or(other))
   }
 
   /// Returns true of false based on whether the limit of allowed adjacents is not exceeded.
@@ -192,36 +378,73 @@ impl Digits {
     self.mapping == other.mapping
   }
 
+  /// Whether this value is a multiple of `divisor`.  The remainder is carried through
+  /// the places one at a time, so no quotient is built however long the value is.
+  ///
+  /// _This will panic if `divisor` is zero._
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::new(base10, "123456789012345678901234567890".to_string());
+  ///
+  /// assert!(num.is_divisible_by(7));
+  /// assert!(!num.is_divisible_by(4));
+  /// ```
+  pub fn is_divisible_by(&self, divisor: u64) -> bool {
+    assert!(divisor != 0, "attempt to divide by zero");
+    let (base, divisor) = (u128::from(self.mapping.base), u128::from(divisor));
+    let remainder = self.as_mapping_vec().iter().fold(0, |acc, &place| (acc * base + u128::from(place)) % divisor);
+    remainder == 0
+  }
+
+  /// Whether this value reads the same in both directions.  Zero padding counts, as
+  /// with `to_s`, so "0110" is a palindrome and "011" is not.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  ///
+  /// assert!(Digits::new(base3.clone(), "abcba".to_string()).is_palindrome());
+  /// assert!(!Digits::new(base3, "abc".to_string()).is_palindrome());
+  /// ```
+  pub fn is_palindrome(&self) -> bool {
+    let places = self.as_mapping_vec();
+    places.iter().eq(places.iter().rev())
+  }
+
   // A non-consuming quick end check.
   // More efficient than calling `is_zero` when this applies.
+  #[allow(clippy::match_like_matches_macro, clippy::redundant_pattern_matching)]
   fn is_end(&self) -> bool {
     self.digit == 0 && match self.left { None => true, _ => false }
   }
 
   /// Returns bool value of if the number is one.
   pub fn is_one(&self) -> bool {
-    if self.digit != 1 { return false }
-    match self.left {
-      None => { true },
-      Some(ref bx) => { bx.is_zero() },
-    }
+    self.digit == 1 && self.nodes().skip(1).all(|d| d.digit == 0)
   }
 
   /// Returns bool value of if the number is zero.
   pub fn is_zero(&self) -> bool {
-    if self.digit != 0 { return false }
-    match self.left {
-      None => { true },
-      Some(ref bx) => { bx.is_zero() },
-    }
+    self.nodes().all(|d| d.digit == 0)
+  }
+
+  /// How many places both operands of a multiplication need before it switches from
+  /// schoolbook to Karatsuba multiplication.  See `set_karatsuba_threshold`.
+  pub fn karatsuba_threshold() -> usize {
+    internal::places::karatsuba_threshold()
   }
 
   /// Returns a `usize` of the total linked list length.
   pub fn length(&self) -> usize {
-    match self.left {
-      None => 1,
-      Some(ref l) => { l.length() + 1 }
-    }
+    self.nodes().count()
   }
 
   /// Give the count for the maximum of the same adjacent characters for this digit.
@@ -243,17 +466,15 @@ impl Digits {
   /// The above example demonstrates that there are 2 adjacent 7s next to a 7
   /// and that is the biggest adjacent set of numbers.
   pub fn max_adjacent(&self) -> usize {
-    self.max_adj(self.digit, 0, 1) - 1
-  }
-
-  fn max_adj(&self, last_num: u64, last_num_count: usize, max_count: usize) -> usize {
-    let mut lnc = last_num_count;
-    if self.digit == last_num { lnc += 1; } else { lnc = 1; }
-    let max_count = std::cmp::max(lnc, max_count);
-    match self.left {
-      None => max_count,
-      Some(ref l) => { l.max_adj(self.digit, lnc, max_count) },
+    let mut last_num = self.digit;
+    let mut count = 0;
+    let mut max_count = 0;
+    for d in self.nodes().skip(1) {
+      if d.digit == last_num { count += 1; } else { count = 0; }
+      max_count = std::cmp::max(count, max_count);
+      last_num = d.digit;
     }
+    max_count
   }
 
   /// Multiply two Digits instances together.
@@ -279,35 +500,76 @@ impl Digits {
   ///
   /// _This will panic if numeric bases are not the same._
   pub fn mul(&self, other: Self) -> Self {
-    self.multiply(other, 0)
+    self.multiply(&other)
   }
 
-  // Internal implementation for multiply. Needs the recursive
-  // value of powers of ten for addition.
-  fn multiply(&self, other: Digits, power_of_ten: usize) -> Self {
-    assert!(self.base() == other.base());
-
-    let mut position: usize = power_of_ten;
-    let mut o = Some(Box::new(other));
-    let mut result = self.zero();
-
-    while let Some(thing) = o.clone() {
-      let (dgt, tail) = thing.head_tail();
-      o = tail;
-
-      let mltply = self.propagate((self.digit * dgt).to_string()).pow_ten(position);
-
-      let current_digit = self.propagate(self.mapping.gen(dgt).to_string());
+  /// Multiplies self by the given Digits in place without cloning.  Like `mul` the
+  /// product has no zero padding.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  ///
+  /// let mut num = Digits::new(base10.clone(), "11".to_string());
+  /// let two = Digits::new(base10, "2".to_string());
+  ///
+  /// num.mul_in_place(&two).mul_in_place(&two);
+  /// assert_eq!(num.to_s(), "44");
+  /// ```
+  ///
+  /// _This will panic if numeric bases are not the same._
+  pub fn mul_in_place(&mut self, other: &Self) -> &mut Self {
+    let product = self.multiply(other);
+    self.assign(product);
+    self
+  }
 
-      if let Some(ref bx) = self.left {
-        result.mut_add_internal(bx.clone().multiply(current_digit, position + 1), true);
-      };
+  /// Multiplies with the crate's own schoolbook and Karatsuba multiplication, switching
+  /// to Karatsuba once both operands have at least `threshold` places.  This ignores
+  /// both the process-wide `set_karatsuba_threshold` and the `num-bigint` feature, so
+  /// it suits comparing thresholds side by side.  Like `mul` the product has no zero
+  /// padding.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let a = Digits::new(base10.clone(), "12345678".repeat(8));
+  /// let b = Digits::new(base10, "87654321".repeat(8));
+  ///
+  /// let schoolbook = a.mul_with_threshold(&b, usize::MAX);
+  /// assert!(a.mul_with_threshold(&b, 4).eq_exact(&schoolbook));
+  /// ```
+  ///
+  /// _This will panic if numeric bases are not the same._
+  pub fn mul_with_threshold(&self, other: &Self, threshold: usize) -> Self {
+    assert!(self.base() == other.base());
+    let places = internal::places::mul_with_threshold(
+      &self.as_mapping_vec(), &other.as_mapping_vec(), self.mapping.base, threshold
+    );
+    Digits::from_places(self.mapping.clone(), &places)
+  }
 
-      result.mut_add_internal( mltply, true );
-      position += 1;
-    }
+  // Internal implementation for multiply.
+  fn multiply(&self, other: &Digits) -> Self {
+    self.with_identities(other, |lhs, rhs| lhs.multiply_same_base(rhs))
+  }
 
-    result
+  #[cfg(feature="num-bigint")]
+  fn multiply_same_base(&self, other: &Digits) -> Self {
+    assert!(self.base() == other.base());
+    bigint::mul(self, other)
+  }
+  #[cfg(not(feature="num-bigint"))]
+  fn multiply_same_base(&self, other: &Digits) -> Self {
+    assert!(self.base() == other.base());
+    let places = internal::places::mul(&self.as_mapping_vec(), &other.as_mapping_vec(), self.mapping.base);
+    Digits::from_places(self.mapping.clone(), &places)
   }
 
   /// Add two Digits instances together.
@@ -333,36 +595,7 @@ impl Digits {
   ///
   /// _This will panic if numeric bases are not the same._
   pub fn mut_add(&mut self, other: Self) -> Self {
-    self.mut_add_internal(other, false)
-  }
-  fn mut_add_internal(&mut self, other: Digits, trim: bool) -> Self {
-    assert!(self.base() == other.base());
-
-    if other.is_end() { return self.clone(); };
-    let (last, rest) = other.head_tail();
-
-    // sums current single digit
-    let added = self.propagate(self.mapping.gen(last + self.digit));
-    let (l, r) = added.head_tail();
-    self.digit = l;
-
-    // sums for left
-    let mut intermediate = Digits::new_zero(self.mapping.clone());
-    if let Some(dg) = r { intermediate.mut_add_internal(dg.replicate(), trim); }
-    if let Some(dg) = rest { intermediate.mut_add_internal(dg.replicate(), trim); }
-
-    match self.left.clone() {
-      Some(bx) => {
-        self.set_left( bx.replicate().mut_add_internal(intermediate, trim).clone(), trim );
-      },
-      None => {
-        if !intermediate.is_zero() {
-          self.set_left( intermediate, trim );
-        }
-      }
-    };
-
-    self.clone()
+    self.add_in_place(&other).clone()
   }
 
   /// Multiply two Digits instances together.
@@ -388,10 +621,7 @@ impl Digits {
   ///
   /// _This will panic if numeric bases are not the same._
   pub fn mut_mul(&mut self, other: Self) -> Self {
-    let (d, r) = self.multiply(other, 0).head_tail();
-    self.digit = d;
-    if let Some(rest) = r { self.set_left(rest.replicate(), true); }
-    self.clone()
+    self.mul_in_place(&other).clone()
   }
 
   /// Creates a new Digits instance with the provided character set and value.
@@ -412,24 +642,8 @@ impl Digits {
   pub fn new<S>(mapping: BaseCustom<char>, number: S) -> Digits
   where S: Into<String> {
     let number = number.into();
-    if number.is_empty() { return Digits { mapping: mapping, digit: 0, left: None }; };
-    let (last, rest) = {
-      let mut n = number.chars().rev();
-      (n.next().unwrap(), n.rev().collect::<String>())
-    };
-
-    let continuation = {
-      if rest.is_empty() {
-        None
-      } else {
-        Some(Box::new(Digits::new(mapping.clone(), rest)))
-      }
-    };
-    Digits {
-      mapping: mapping.clone(),
-      digit: mapping.decimal(last.to_string()),
-      left: continuation,
-    }
+    let places: Vec<u64> = number.chars().map(|c| mapping.decimal(c.to_string())).collect();
+    Digits::from_places(Arc::new(mapping), &places)
   }
 
   /// Create a Digits from a Vector of from zero positional mappings for custom Digits numeric
@@ -457,12 +671,7 @@ impl Digits {
     if places.iter().any(|&x| x >= self.mapping.base) {
       return Err("Character mapping out of range!");
     }
-    let num = places.iter().fold("".to_string(), |mut acc, &x| {
-        acc.push(*self.mapping.nth(x as usize).unwrap());
-        acc
-      }
-    );
-    Ok(Digits::new(self.mapping.clone(), num))
+    Ok(Digits::from_places(self.mapping.clone(), places))
   }
 
   /// Creates a new Digits instance with value of one and the provided character mapping.
@@ -478,7 +687,7 @@ impl Digits {
   /// assert_eq!(one.to_s(), "1");
   /// ```
   pub fn new_one(mapping: BaseCustom<char>) -> Self {
-    Digits { mapping: mapping, digit: 1, left: None }
+    Digits { mapping: Arc::new(mapping), digit: 1, left: None }
   }
 
   /// Creates a new Digits instance with value of zero and uses the provided character mapping.
@@ -494,7 +703,7 @@ impl Digits {
   /// assert_eq!(zero.to_s(), "0");
   /// ```
   pub fn new_zero(mapping: BaseCustom<char>) -> Self {
-    Digits { mapping: mapping, digit: 0, left: None }
+    Digits { mapping: Arc::new(mapping), digit: 0, left: None }
   }
 
   /// Returns the next Digits in incrementing that only allows the given number of
@@ -513,8 +722,19 @@ impl Digits {
   /// assert_eq!(num.next_non_adjacent(0).to_s(), "101");
   /// ```
   pub fn next_non_adjacent(&mut self, adjacent: usize) -> Self {
-    self.prep_non_adjacent(adjacent);
-    self.step_non_adjacent(adjacent)
+    self.next_non_adjacent_in_place(adjacent).clone()
+  }
+
+  /// The non-cloning form of `next_non_adjacent`.
+  ///
+  /// _This will panic! if numeric base is less than 4._
+  pub fn next_non_adjacent_in_place(&mut self, adjacent: usize) -> &mut Self {
+    self.prep_non_adjacent_in_place(adjacent).step_non_adjacent_in_place(adjacent)
+  }
+
+  // Walks the linked list from the pinky leftwards.
+  fn nodes(&self) -> impl Iterator<Item = &Digits> {
+    std::iter::successors(Some(self), |d| d.left.as_deref())
   }
 
   /// Creates a new Digits instance with value of one and uses the current character mapping.
@@ -531,7 +751,7 @@ impl Digits {
   /// assert_eq!(one.to_s(), "1");
   /// ```
   pub fn one(&self) -> Self {
-    Digits::new_one(self.mapping.clone())
+    Digits { mapping: self.mapping.clone(), digit: 1, left: None }
   }
 
   /// The “pinky” is the smallest digit
@@ -542,7 +762,9 @@ impl Digits {
     self.mapping.char(self.digit as usize).unwrap()
   }
 
-  /// Multiplies self times the power-of given Digits parameter.
+  /// Multiplies self times the power-of given Digits parameter.  Self is updated to the
+  /// result and a copy of it is returned, so a power of zero leaves self as an unpadded
+  /// one in its own character mapping.
   ///
   /// # Example
   ///
@@ -562,44 +784,79 @@ impl Digits {
   /// ```text
   /// "121"
   /// ```
-  pub fn pow(&mut self, mut pwr: Self) -> Self {
-    if pwr.is_zero() { return self.one(); }
-    let copy = self.clone();
-    loop {
-      if pwr.is_one() {
-        break
-      } else {
-        self.mut_mul(copy.clone());
+  pub fn pow(&mut self, pwr: Self) -> Self {
+    self.pow_in_place(&pwr).clone()
+  }
+
+  /// Raises self to the power of the given Digits in place without cloning.  A power of
+  /// zero sets self to one without any zero padding.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  ///
+  /// let mut num = Digits::new(base10.clone(), "3".to_string());
+  /// let two = Digits::new(base10, "2".to_string());
+  ///
+  /// num.pow_in_place(&two).pow_in_place(&two);
+  /// assert_eq!(num.to_s(), "81");
+  /// ```
+  pub fn pow_in_place(&mut self, pwr: &Self) -> &mut Self {
+    if pwr.is_zero() {
+      let one = self.one();
+      self.assign(one);
+      return self;
+    }
+
+    #[cfg(feature="num-bigint")]
+    {
+      if let Some(result) = bigint::pow(self, pwr) {
+        self.assign(result);
+        return self;
       }
-      pwr.pred_till_zero();
     }
-    self.clone()
-  }
 
-  // multiply self by 10ⁿ without using typical multiplication
-  fn pow_ten(&self, positions: usize) -> Self {
-    let mut result: Digits = self.clone();
-    for _ in 0..positions {
-      let original = result;
-      result = Digits::new_zero(self.mapping.clone());
-      result.set_left(original, true);
+    let copy = self.clone();
+    let mut pwr = pwr.clone();
+    while !pwr.is_one() {
+      self.mul_in_place(&copy);
+      pwr.pred_till_zero_in_place();
     }
-    result
+    self
   }
 
   /// Minuses one unless it's zero, then it just returns a Digits instance of zero.
   pub fn pred_till_zero(&mut self) -> Self {
-    if self.is_zero() { return self.clone(); }
-    if self.digit == 0 {
-      self.digit = self.mapping.base - 1;
-      match self.left.clone() {
-        Some(ref mut bx) => self.set_left(bx.pred_till_zero(), false),
-        None => self.left = None
-      }
-    } else {
-      self.digit -= 1;
+    self.pred_till_zero_in_place().clone()
+  }
+
+  /// Minuses one in place unless it's zero, without cloning.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let mut num = Digits::new(base10, "0101".to_string());
+  ///
+  /// num.pred_till_zero_in_place().pred_till_zero_in_place();
+  /// assert_eq!(num.to_s(), "0099");
+  /// ```
+  pub fn pred_till_zero_in_place(&mut self) -> &mut Self {
+    // Only the trailing zeros are visited, so this is amortised O(1) per step.
+    if self.is_zero() { return self; }
+    let top = self.mapping.base - 1;
+    let mut node: &mut Digits = self;
+    while node.digit == 0 {
+      node.digit = top;
+      node = node.left.as_mut().unwrap();
     }
-    self.clone()
+    node.digit -= 1;
+    self
   }
 
   /// Sometimes given starting Digits have more adjacent characters than is desired
@@ -630,10 +887,18 @@ impl Digits {
   ///
   /// For convenience you may just use `next_non_adjacent` instead of prep and step.
   pub fn prep_non_adjacent(&mut self, adjacent: usize) -> Self {
+    self.prep_non_adjacent_in_place(adjacent).clone()
+  }
+
+  /// The non-cloning form of `prep_non_adjacent`.
+  ///
+  /// _This will panic! if numeric base is less than 4._
+  #[allow(clippy::partialeq_to_none, clippy::unnecessary_to_owned)]
+  pub fn prep_non_adjacent_in_place(&mut self, adjacent: usize) -> &mut Self {
     assert!(self.mapping.base > 3, "\n\n  WARNING!\n\n  \"You may not use non-adjacent stepping with numeric bases of less than 4!\"\n\n");
 
     if self.is_valid_adjacent(adjacent) {
-      return self.clone();
+      return self;
     }
 
     let mut v = self.as_mapping_vec();
@@ -659,7 +924,7 @@ impl Digits {
           if last_num_count > adjacent {
             let i = i + 1;
             let mut d = self.new_mapped(&v[0..i].to_vec()).ok().unwrap();
-            d.succ();
+            d.succ_in_place();
             let mut new_v = d.as_mapping_vec();
 
             for _ in v[i..v.len()].iter() {
@@ -675,10 +940,9 @@ impl Digits {
       }
       break;
     }
-    let result = self.new_mapped(&v).ok().unwrap().pred_till_zero();
-    self.digit = result.digit;
-    self.left = result.left.clone();
-    result
+    let result = self.new_mapped(&v).ok().unwrap();
+    self.assign(result);
+    self.pred_till_zero_in_place()
   }
 
   /// Creates a new Digits instance with the internal character set and given value.
@@ -698,7 +962,9 @@ impl Digits {
   /// ```
   pub fn propagate<S>(&self, number: S) -> Self
   where S: Into<String> {
-    Digits::new(self.mapping.clone(), number)
+    let number = number.into();
+    let places: Vec<u64> = number.chars().map(|c| self.mapping.decimal(c.to_string())).collect();
+    Digits::from_places(self.mapping.clone(), &places)
   }
 
   /// Right count of digits character index.
@@ -723,19 +989,35 @@ impl Digits {
   /// 3
   /// ```
   pub fn rcount(&self, character_index: u8) -> usize {
-    if let Some(ref d) = self.left {
-      if self.digit == u64::from(character_index) {
-        return d.rcount(character_index) + 1;
-      }
-    } else if self.digit == u64::from(character_index) {
-      return 1;
-    }
-    0
+    self.nodes().take_while(|d| d.digit == u64::from(character_index)).count()
   }
 
   /// An alias for `clone`. _Useful for unboxing._
   pub fn replicate(self) -> Self { self.clone() }
 
+  /// Sets how many places both operands of a multiplication need before it switches
+  /// from schoolbook to Karatsuba multiplication, for the whole process.  Schoolbook
+  /// is quicker for short values; the default of 32 places suits most bases, and
+  /// `cargo bench --bench mul` measures both sides of it.
+  ///
+  /// With the `num-bigint` feature enabled `*`, `mul` and `pow` go through `BigUint`
+  /// and this setting has no effect on them.  As it is shared by every thread, use
+  /// `mul_with_threshold` to pick a threshold for a single multiplication.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let default = Digits::karatsuba_threshold();
+  /// Digits::set_karatsuba_threshold(64);
+  /// assert_eq!(Digits::karatsuba_threshold(), 64);
+  /// Digits::set_karatsuba_threshold(default);
+  /// ```
+  pub fn set_karatsuba_threshold(places: usize) {
+    internal::places::set_karatsuba_threshold(places);
+  }
+
   // logic for setting left linked list continuation
   fn set_left(&mut self, d: Digits, trim: bool) {
     if trim && d.is_end() {
@@ -745,6 +1027,103 @@ impl Digits {
     }
   }
 
+  /// Shifts the value left by `n` places, multiplying it by the base to the power of `n`.
+  /// Zero places are appended at the least significant end and any zero padding is
+  /// kept, so "0042" becomes "004200".
+  ///
+  /// Zero is the one exception: it becomes a single "0" and loses its padding, so
+  /// "000" shifted by two is "0" rather than "00000".
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  /// let num = Digits::new(base16.clone(), "BEE".to_string());
+  ///
+  /// assert_eq!(num.shl_digits(2).to_s(), "BEE00");
+  /// assert_eq!((num << 1).to_s(), "BEE0");
+  ///
+  /// let zero = Digits::new(base16, "000".to_string());
+  /// assert_eq!(zero.shl_digits(2).to_s(), "0");
+  /// ```
+  pub fn shl_digits(&self, n: usize) -> Self {
+    let mut result = self.clone();
+    result.shl_digits_in_place(n);
+    result
+  }
+
+  /// The non-cloning form of `shl_digits`.
+  pub fn shl_digits_in_place(&mut self, n: usize) -> &mut Self {
+    if self.is_zero() {
+      self.left = None;
+      return self;
+    }
+    for _ in 0..n {
+      let zero = Digits { mapping: self.mapping.clone(), digit: 0, left: None };
+      let previous = std::mem::replace(self, zero);
+      self.left = Some(Box::new(previous));
+    }
+    self
+  }
+
+  /// Shifts the value right by `n` places, dividing it by the base to the power of `n`
+  /// and dropping the remainder.  Shifting out every place leaves zero.  Use
+  /// `split_at_position` to keep the dropped places.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::new(base10, "0012345".to_string());
+  ///
+  /// assert_eq!(num.shr_digits(3).to_s(), "0012");
+  /// assert_eq!(num.shr_digits(9).to_s(), "0");
+  /// ```
+  pub fn shr_digits(&self, n: usize) -> Self {
+    let mut result = self.clone();
+    result.shr_digits_in_place(n);
+    result
+  }
+
+  /// The non-cloning form of `shr_digits`.
+  pub fn shr_digits_in_place(&mut self, n: usize) -> &mut Self {
+    for _ in 0..n {
+      match self.left.take() {
+        Some(next) => *self = *next,
+        None => { self.digit = 0; break; },
+      }
+    }
+    self
+  }
+
+  /// Splits the value `n` places from its least significant end into the quotient and
+  /// remainder of dividing it by the base to the power of `n`.  The quotient is
+  /// `shr_digits(n)`; the remainder is the `n` lowest places with their zero padding.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::new(base10, "1234007".to_string());
+  ///
+  /// let (high, low) = num.split_at_position(4);
+  /// assert_eq!((high.to_s(), low.to_s()), ("123".to_string(), "4007".to_string()));
+  /// ```
+  pub fn split_at_position(&self, n: usize) -> (Self, Self) {
+    let places = self.as_mapping_vec();
+    let split = places.len().saturating_sub(n);
+    (
+      Digits::from_places(self.mapping.clone(), &places[..split]),
+      Digits::from_places(self.mapping.clone(), &places[split..]),
+    )
+  }
+
   /// Returns the next Digits in incrementing that only allows the given number of
   /// adjacent number duplicates.
   ///
@@ -765,40 +1144,103 @@ impl Digits {
   /// assert_eq!(num.step_non_adjacent(0).to_s(), "101");
   /// ```
   pub fn step_non_adjacent(&mut self, adjacent: usize) -> Self {
+    self.step_non_adjacent_in_place(adjacent).clone()
+  }
+
+  /// The non-cloning form of `step_non_adjacent`.
+  ///
+  /// _This will panic! if numeric base is less than 4._
+  pub fn step_non_adjacent_in_place(&mut self, adjacent: usize) -> &mut Self {
     let mut step_map = StepMap::new(self.zero(), adjacent as u8);
     let mut v: Self;
     loop {
-      let mut builder = self.clone();
-      v = builder.mut_add(step_map.next().unwrap());
+      v = self.clone();
+      v.add_in_place(&step_map.next().unwrap());
       if v.is_valid_adjacent(adjacent) {
         break;
       }
     }
-    self.digit = v.digit;
-    self.left = v.left;
-    self.clone()
+    self.assign(v);
+    self
   }
 
   /// Plus one.
   pub fn succ(&mut self) -> Self {
-    let one = self.one();
-    self.mut_add_internal(one, false)
+    self.succ_in_place().clone()
+  }
+
+  /// Plus one in place without cloning.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let mut num = Digits::new(base10, "0098".to_string());
+  ///
+  /// num.succ_in_place().succ_in_place();
+  /// assert_eq!(num.to_s(), "0100");
+  /// ```
+  pub fn succ_in_place(&mut self) -> &mut Self {
+    // Carries over the trailing highest characters only, so this is amortised O(1)
+    // per step.
+    let top = self.mapping.base - 1;
+    let mut node: &mut Digits = self;
+    while node.digit == top {
+      node.digit = 0;
+      if node.left.is_none() {
+        let mapping = node.mapping.clone();
+        node.left = Some(Box::new(Digits { mapping, digit: 1, left: None }));
+        return self;
+      }
+      node = node.left.as_mut().unwrap();
+    }
+    node.digit += 1;
+    self
   }
 
   /// Gives the full value of all digits within the linked list as a String.
   pub fn to_s(&self) -> String {
-    let num = self.mapping.gen(self.digit);
-    match self.left {
-      None => num.to_owned(),
-      Some(ref bx) => format!("{}{}", bx.to_s(), num),
-    }
+    let mut chars: Vec<char> = self.nodes().map(|d| self.mapping.char(d.digit as usize).unwrap()).collect();
+    chars.reverse();
+    chars.into_iter().collect()
   }
 
   /// Gives the full value of all digits within the linked list as a String.
+  #[allow(clippy::inherent_to_string_shadow_display)]
   pub fn to_string(&self) -> String {
     self.to_s()
   }
 
+  /// Compares two Digits instances by value.  Returns an `Err(&'static str)` Result
+  /// rather than panicking when the character mappings differ.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use std::cmp::Ordering;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  ///
+  /// let nine = Digits::new(base10.clone(), "9".to_string());
+  /// let ten = Digits::new(base10, "10".to_string());
+  /// let hex_nine = Digits::new(base16, "9".to_string());
+  ///
+  /// assert_eq!(nine.try_cmp(&ten), Ok(Ordering::Less));
+  /// assert!(nine.try_cmp(&hex_nine).is_err());
+  /// ```
+  pub fn try_cmp(&self, other: &Self) -> Result<Ordering, &'static str> {
+    self.with_identities(other, |lhs, rhs| {
+      if !lhs.is_compat(rhs) {
+        return Err("Incompatible numeric base mappings!");
+      }
+      Ok(lhs.cmp_digits(rhs))
+    })
+  }
+
   /// Creates a new Digits instance with value of zero and the current character mapping.
   ///
   /// # Example
@@ -813,7 +1255,7 @@ impl Digits {
   /// assert_eq!(zero.to_s(), "0");
   /// ```
   pub fn zero(&self) -> Self {
-    Digits::new_zero(self.mapping.clone())
+    Digits { mapping: self.mapping.clone(), digit: 0, left: None }
   }
 
   /// Zero fills the left of the current number up to a total character length.
@@ -830,25 +1272,12 @@ impl Digits {
   /// assert_eq!(nine.to_s(), "0009");
   /// ```
   pub fn zero_fill(&mut self, length: usize) {
-    if self.length() >= length { return; }
-    if length == 0 { return; }
-    match self.left.clone() {
-      None => {
-        let mut l = self.zero();
-        l.zero_fill(length - 1);
-        self.left = Some(Box::new(l));
-      }
-      Some(v) => {
-        self.set_left(
-          {
-            let mut l = v.replicate();
-            l.zero_fill(length -1 );
-            l
-          },
-          false
-        )
-      }
-    }
+    let current = self.length();
+    if current >= length { return; }
+    let mut places = vec![0; length - current];
+    places.extend(self.as_mapping_vec());
+    let filled = Digits::from_places(self.mapping.clone(), &places);
+    self.assign(filled);
   }
 
   /// Zero trims the left of the current number.
@@ -894,27 +1323,10 @@ pub trait Reverse {
 
 impl Reverse for Digits {
   fn reverse(&mut self) {
-    let mut curr_node: Option<Digits> = Some(self.clone());
-    let mut prev_node: Option<Digits> = None;
-
-    while curr_node != None {
-      let cn = curr_node.unwrap();
-      let next_node = if let Some(n) = cn.clone().left {
-        Some(n.replicate())
-      } else { None };
-      let mut c = cn;
-      if let Some(prev) = prev_node {
-        c.set_left(prev, false);
-      } else {
-        c.left = None;
-      }
-      prev_node = Some(c);
-      curr_node = next_node;
-    }
-
-    let p = prev_node.unwrap();
-    self.digit = p.digit;
-    self.left = p.left;
+    let mut places = self.as_mapping_vec();
+    places.reverse();
+    let reversed = Digits::from_places(self.mapping.clone(), &places);
+    self.assign(reversed);
   }
 }
 
@@ -933,50 +1345,14 @@ impl From<(BaseCustom<char>, u64)> for Digits {
 
 impl From<(BaseCustom<char>, Digits)> for Digits {
   fn from(d: (BaseCustom<char>, Digits)) -> Digits {
-    let mapping = d.0;
-    let source = d.1;
-    let from_base = source.mapping.base;
-    let mut result = Digits::new_zero(mapping.clone());
-    let mut pointer: Option<Box<Digits>> = Some(Box::new(source.clone()));
-    let mut position = 0;
-    // Down-Casting
-    if from_base >= mapping.base {
-      while let Some(bx) = pointer {
-        let (h, t) = bx.head_tail();
-        if h != 0 { // speed optimization
-          result.mut_add_internal(
-            Digits::new(mapping.clone(), mapping.gen(h)).mul(
-              Digits::new(mapping.clone(), mapping.gen(from_base)).
-                pow(source.gen(position))
-            ),
-            true
-          );
-        }
-        position += 1;
-        pointer = t;
-      }
-    } else { // Up-Casting
-      while let Some(bx) = pointer {
-        let (h, t) = bx.head_tail();
-        if h != 0 { // speed optimization
-          result.mut_add_internal(
-            // This implementation is limited by the max of usize
-            Digits::new(mapping.clone(), mapping.gen(h * from_base.pow(position as u32))),
-            true
-          );
-        }
-        position += 1;
-        pointer = t;
-      }
-    }
-    result
+    Digits::convert_mapping(d.0, d.1)
   }
 }
 
 impl From<(Digits, Digits)> for Digits {
   fn from(d: (Digits, Digits)) -> Digits {
     if d.0.base() == d.1.base() { return d.1; }
-    Digits::from((d.0.mapping, d.1))
+    Digits::from(((*d.0.mapping).clone(), d.1))
   }
 }
 
@@ -998,6 +1374,23 @@ impl Into<String> for String {
   }
 }
 
+impl Clone for Digits {
+  fn clone(&self) -> Digits {
+    Digits::from_places(self.mapping.clone(), &self.as_mapping_vec())
+  }
+}
+
+/// Unlinks the list one position at a time so dropping a long value does not
+/// recurse through every `Box`.
+impl Drop for Digits {
+  fn drop(&mut self) {
+    let mut next = self.left.take();
+    while let Some(mut node) = next {
+      next = node.left.take();
+    }
+  }
+}
+
 impl fmt::Display for Digits {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
    write!(f, "Digits — (Character: '{}', Decimal Value: {}{})",
@@ -1017,75 +1410,170 @@ impl fmt::Debug for Digits {
   }
 }
 
+/// Equality is by value so leading zeros are insignificant; "007" == "7".
+/// Use `eq_exact` when the zero padding matters.  With the `num-traits` feature a
+/// `Zero` or `One` constant equals the same value in any mapping.
 impl PartialEq for Digits {
   fn eq(&self, other: &Digits) -> bool {
-    self.mapping == other.mapping &&
-      self.digit == other.digit &&
-      self.left == other.left
+    self.with_identities(other, |lhs, rhs| {
+      lhs.mapping == rhs.mapping &&
+        lhs.cmp_digits(rhs) == Ordering::Equal
+    })
+  }
+}
+
+impl Eq for Digits {}
+
+/// Hashes the value alone, without its zero padding or character mapping, so that it
+/// agrees with `==` where a num-traits identity equals a value of any mapping.
+impl Hash for Digits {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    // The value modulo the Mersenne prime 2^61 - 1 is the same whatever the base.
+    const MODULUS: u128 = (1 << 61) - 1;
+    let base = u128::from(self.mapping.base);
+    let residue = self.as_mapping_vec().iter()
+      .fold(0u128, |acc, &place| (acc * base + u128::from(place)) % MODULUS);
+    (residue as u64).hash(state);
   }
 }
 
 impl Add for Digits {
   type Output = Self;
-  fn add(self, other: Self) -> Self {
-    self.clone().mut_add(other)
+  fn add(mut self, other: Self) -> Self {
+    self.add_in_place(&other);
+    self
   }
 }
 
 impl AddAssign for Digits {
   fn add_assign(&mut self, other: Self) {
-    self.mut_add(other);
+    self.add_in_place(&other);
   }
 }
 
 impl Mul for Digits {
   type Output = Self;
   fn mul(self, other: Self) -> Self {
-    self.multiply(other, 0)
+    self.multiply(&other)
   }
 }
 
 impl MulAssign for Digits {
   fn mul_assign(&mut self, other: Self) {
-    self.mut_mul(other);
+    self.mul_in_place(&other);
+  }
+}
+
+/// Subtraction keeps the zero padding of the left hand side.
+///
+/// _This will panic if numeric bases are not the same or if the result would be
+/// below zero._
+impl Sub for Digits {
+  type Output = Self;
+  fn sub(self, other: Self) -> Self {
+    self.with_identities(&other, |lhs, rhs| {
+      assert!(lhs.base() == rhs.base());
+      let places = internal::places::sub(
+        &lhs.as_mapping_vec(), &rhs.as_mapping_vec(), lhs.mapping.base
+      ).expect("attempt to subtract with overflow");
+      lhs.new_mapped(&places).unwrap()
+    })
+  }
+}
+
+/// _This will panic if numeric bases are not the same or when dividing by zero._
+impl Div for Digits {
+  type Output = Self;
+  fn div(self, other: Self) -> Self {
+    self.with_identities(&other, |lhs, rhs| {
+      assert!(lhs.base() == rhs.base());
+      let (quotient, _) = internal::places::div_rem(
+        &lhs.as_mapping_vec(), &rhs.as_mapping_vec(), lhs.mapping.base
+      ).expect("attempt to divide by zero");
+      lhs.new_mapped(&quotient).unwrap()
+    })
+  }
+}
+
+/// _This will panic if numeric bases are not the same or when dividing by zero._
+impl Rem for Digits {
+  type Output = Self;
+  fn rem(self, other: Self) -> Self {
+    self.with_identities(&other, |lhs, rhs| {
+      assert!(lhs.base() == rhs.base());
+      let (_, remainder) = internal::places::div_rem(
+        &lhs.as_mapping_vec(), &rhs.as_mapping_vec(), lhs.mapping.base
+      ).expect("attempt to calculate the remainder with a divisor of zero");
+      lhs.new_mapped(&remainder).unwrap()
+    })
   }
 }
 
 impl BitXor for Digits {
   type Output = Self;
-  fn bitxor(self, other: Self) -> Self {
-    self.clone().pow(other)
+  fn bitxor(mut self, other: Self) -> Self {
+    self.pow_in_place(&other);
+    self
   }
 }
 
 impl BitXorAssign for Digits {
   fn bitxor_assign(&mut self, other: Self) {
-    self.pow(other);
+    self.pow_in_place(&other);
   }
 }
 
+/// Multiplies by the base to the power of the shift, as `shl_digits`.  Zero padding
+/// is kept, except that zero becomes a single "0".
+impl Shl<usize> for Digits {
+  type Output = Self;
+  fn shl(mut self, n: usize) -> Self {
+    self.shl_digits_in_place(n);
+    self
+  }
+}
+
+impl ShlAssign<usize> for Digits {
+  fn shl_assign(&mut self, n: usize) {
+    self.shl_digits_in_place(n);
+  }
+}
+
+/// Divides by the base to the power of the shift, as `shr_digits`.
+impl Shr<usize> for Digits {
+  type Output = Self;
+  fn shr(mut self, n: usize) -> Self {
+    self.shr_digits_in_place(n);
+    self
+  }
+}
+
+impl ShrAssign<usize> for Digits {
+  fn shr_assign(&mut self, n: usize) {
+    self.shr_digits_in_place(n);
+  }
+}
+
+/// Returns `None` when the character mappings differ.
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Digits {
   fn partial_cmp(&self, other: &Digits) -> Option<Ordering> {
-    assert!(self.mapping == other.mapping);
-    let mut result: Option<Ordering>;
-    let mut a: Self = self.clone();
-    let mut b: Self = other.clone();
-    result = a.digit.partial_cmp(&b.digit);
-    while let (Some(x),Some(y)) = (a.left.clone(), b.left.clone()) {
-      a = x.replicate();
-      b = y.replicate();
-      match a.digit.partial_cmp(&b.digit) {
-        Some(Ordering::Equal) | None => (),
-        Some(change) => { result = Some(change); },
-      }
-    }
-    if a.left.is_some() && !b.left.is_some() && !a.left.clone().unwrap().is_zero() {
-      result = Some(Ordering::Greater);
-    }
-    if !a.left.is_some() && b.left.is_some() && !b.left.unwrap().is_zero() {
-      result = Some(Ordering::Less);
-    }
-    result
+    self.try_cmp(other).ok()
+  }
+}
+
+/// Ordering is by value so leading zeros are insignificant.
+///
+/// _This will panic if the character mappings are not the same_, which includes
+/// sorting or using as `BTreeMap` keys Digits of different mappings.  `<` and the
+/// other comparison operators go through `PartialOrd` and do not panic.  Use
+/// `try_cmp` or `cmp_value` to compare Digits with different character mappings.
+impl Ord for Digits {
+  fn cmp(&self, other: &Digits) -> Ordering {
+    self.with_identities(other, |lhs, rhs| {
+      assert!(lhs.mapping == rhs.mapping, "Incompatible numeric base mappings!");
+      lhs.cmp_digits(rhs)
+    })
   }
 }
 
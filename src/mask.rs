@@ -0,0 +1,148 @@
+//! Hashcat style masks where each position draws from its own character set.
+//!
+//! | Placeholder | Characters                                  |
+//! |-------------|---------------------------------------------|
+//! | `?l`        | `abcdefghijklmnopqrstuvwxyz`                |
+//! | `?u`        | `ABCDEFGHIJKLMNOPQRSTUVWXYZ`                |
+//! | `?d`        | `0123456789`                                |
+//! | `?h`        | `0123456789abcdef`                          |
+//! | `?H`        | `0123456789ABCDEF`                          |
+//! | `?s`        | space and ``!"#$%&'()*+,-./:;<=>?@[\]^_`{|}~`` |
+//! | `?a`        | `?l?u?d?s`                                  |
+//! | `??`        | a literal `?`                               |
+//!
+//! Any other character stands for itself.
+use std::iter::FromIterator;
+use radices::decimal_base;
+use Digits;
+
+const LOWER: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPER: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SPECIAL: &str = " !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// A parsed mask.  Enumerating it counts up like an odometer with the last position
+/// changing fastest.
+///
+/// # Example
+///
+/// ```
+/// use digits::mask::Mask;
+///
+/// let mask = Mask::new("?d-?h").unwrap();
+/// assert_eq!(mask.keyspace().to_s(), "160");
+///
+/// let first: Vec<String> = mask.iter().take(3).collect();
+/// assert_eq!(first, vec!["0-0", "0-1", "0-2"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mask {
+  pattern: String,
+  positions: Vec<Vec<char>>,
+}
+
+impl Mask {
+  /// Parses a mask pattern.
+  ///
+  /// Returns an `Err(&'static str)` Result for an unknown `?` placeholder or an empty pattern.
+  pub fn new(pattern: &str) -> Result<Self, &'static str> {
+    let mut positions = vec![];
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+      if c != '?' {
+        positions.push(vec![c]);
+        continue;
+      }
+      let set: String = match chars.next() {
+        Some('l') => LOWER.to_string(),
+        Some('u') => UPPER.to_string(),
+        Some('d') => DIGITS.to_string(),
+        Some('h') => format!("{}abcdef", DIGITS),
+        Some('H') => format!("{}ABCDEF", DIGITS),
+        Some('s') => SPECIAL.to_string(),
+        Some('a') => [LOWER, UPPER, DIGITS, SPECIAL].concat(),
+        Some('?') => "?".to_string(),
+        Some(_) => return Err("Unknown mask placeholder!"),
+        None => return Err("Mask ends with an unfinished placeholder!"),
+      };
+      positions.push(set.chars().collect());
+    }
+    if positions.is_empty() { return Err("A mask needs at least one position!"); }
+    Ok(Mask { pattern: pattern.to_string(), positions })
+  }
+
+  /// The pattern the mask was parsed from.
+  pub fn pattern(&self) -> &str {
+    &self.pattern
+  }
+
+  /// The characters each position draws from, most significant first.
+  pub fn positions(&self) -> &[Vec<char>] {
+    &self.positions
+  }
+
+  /// The number of candidates the mask describes, in base 10.
+  pub fn keyspace(&self) -> Digits {
+    let mut total = Digits::new_one(decimal_base());
+    for set in &self.positions {
+      total.mul_in_place(&Digits::from((decimal_base(), set.len() as u64)));
+    }
+    total
+  }
+
+  /// Iterates over every candidate of the mask in order.
+  pub fn iter(&self) -> MaskIter {
+    MaskIter { mask: self.clone(), indices: Some(vec![0; self.positions.len()]) }
+  }
+
+  /// Iterates from the given position, as returned by `MaskIter::position`, onwards.
+  ///
+  /// Returns an `Err(&'static str)` Result if the position does not fit the mask.
+  pub fn iter_from(&self, position: &[usize]) -> Result<MaskIter, &'static str> {
+    let fits = position.len() == self.positions.len() &&
+      position.iter().zip(&self.positions).all(|(&i, set)| i < set.len());
+    if !fits { return Err("Position does not fit the mask!"); }
+    Ok(MaskIter { mask: self.clone(), indices: Some(position.to_vec()) })
+  }
+
+  // An iterator that has already yielded every candidate.
+  pub(crate) fn iter_finished(&self) -> MaskIter {
+    MaskIter { mask: self.clone(), indices: None }
+  }
+}
+
+/// An iterator over every candidate of a `Mask`, created by `Mask::iter`.
+#[derive(Clone, Debug)]
+pub struct MaskIter {
+  mask: Mask,
+  indices: Option<Vec<usize>>,
+}
+
+impl MaskIter {
+  /// The index into each position's characters of the candidate `next` will yield,
+  /// or `None` once every candidate has been yielded.
+  pub fn position(&self) -> Option<&[usize]> {
+    self.indices.as_deref()
+  }
+}
+
+impl Iterator for MaskIter {
+  type Item = String;
+
+  fn next(&mut self) -> Option<String> {
+    let current = {
+      let indices = self.indices.as_ref()?;
+      String::from_iter(indices.iter().zip(&self.mask.positions).map(|(&i, set)| set[i]))
+    };
+    let mut finished = true;
+    if let Some(ref mut indices) = self.indices {
+      for (i, set) in indices.iter_mut().zip(&self.mask.positions).rev() {
+        *i += 1;
+        if *i < set.len() { finished = false; break; }
+        *i = 0;
+      }
+    }
+    if finished { self.indices = None; }
+    Some(current)
+  }
+}
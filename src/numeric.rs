@@ -0,0 +1,242 @@
+//! `num-traits` implementations for `Digits`.
+//!
+//! The constructors without a `self` to take a mapping from (`from_u64` and so on)
+//! use the decimal mapping, the same as `Default`.
+//!
+//! `Zero::zero()`, `One::one()` and any constant computed only from them, such as
+//! `one + one`, have no mapping of their own.  Alone they read as decimal.  Met with a
+//! value that has a mapping, in arithmetic or a comparison, they are converted into
+//! that value's mapping first, so generic code works in any alphabet.
+//!
+//! `Bounded` needs a width and an alphabet known from the type alone, which `Fixed`
+//! provides for the standard alphabets in `radices`.
+use std::marker::PhantomData;
+use std::sync::{Arc,OnceLock};
+use num_traits::{Zero,One,Num,Bounded,CheckedAdd,CheckedMul,Pow,ToPrimitive,FromPrimitive};
+use super::{BaseCustom,Digits,radices};
+
+// The mapping shared by every `Zero::zero()` and `One::one()` and every value computed
+// from them alone.  It is told apart from an ordinary decimal mapping by address.
+fn identity_mapping() -> &'static Arc<BaseCustom<char>> {
+  static IDENTITY: OnceLock<Arc<BaseCustom<char>>> = OnceLock::new();
+  IDENTITY.get_or_init(|| Arc::new(radices::decimal_base()))
+}
+
+// Whether `d` is a constant without a mapping of its own.
+pub(crate) fn is_identity(d: &Digits) -> bool {
+  Arc::ptr_eq(&d.mapping, identity_mapping())
+}
+
+impl Zero for Digits {
+  fn zero() -> Self {
+    Digits { mapping: identity_mapping().clone(), digit: 0, left: None }
+  }
+
+  fn is_zero(&self) -> bool {
+    Digits::is_zero(self)
+  }
+}
+
+impl One for Digits {
+  fn one() -> Self {
+    Digits { mapping: identity_mapping().clone(), digit: 1, left: None }
+  }
+
+  fn is_one(&self) -> bool {
+    Digits::is_one(self)
+  }
+}
+
+fn maps_char(mapping: &BaseCustom<char>, c: char) -> bool {
+  (0..mapping.base as usize).any(|i| mapping.nth(i) == Some(&c))
+}
+
+impl Num for Digits {
+  type FromStrRadixErr = &'static str;
+
+  // Radix 16 uses the lowercase mapping when every letter given is lowercase, and the
+  // uppercase mapping otherwise, so mixed case input is accepted.
+  fn from_str_radix(s: &str, radix: u32) -> Result<Self, &'static str> {
+    let (mapping, s) = match radix {
+      2 => (radices::binary_base(), s.to_string()),
+      8 => (radices::octal_base(), s.to_string()),
+      10 => (radices::decimal_base(), s.to_string()),
+      16 if !s.chars().any(|c| c.is_ascii_uppercase()) => (radices::hexl_base(), s.to_string()),
+      16 => (radices::hex_base(), s.to_ascii_uppercase()),
+      _ => return Err("Unsupported radix!  Only 2, 8, 10 and 16 are available."),
+    };
+    if s.is_empty() || !s.chars().all(|c| maps_char(&mapping, c)) {
+      return Err("Invalid character for the given radix!");
+    }
+    Ok(Digits::new(mapping, s))
+  }
+}
+
+impl CheckedAdd for Digits {
+  fn checked_add(&self, v: &Self) -> Option<Self> {
+    if !self.is_compat(v) && !is_identity(self) && !is_identity(v) { return None; }
+    let mut sum = self.clone();
+    sum.add_in_place(v);
+    Some(sum)
+  }
+}
+
+impl CheckedMul for Digits {
+  fn checked_mul(&self, v: &Self) -> Option<Self> {
+    if !self.is_compat(v) && !is_identity(self) && !is_identity(v) { return None; }
+    Some(self.multiply(v))
+  }
+}
+
+impl Pow<Digits> for Digits {
+  type Output = Digits;
+  fn pow(self, rhs: Digits) -> Digits {
+    let mut base = self;
+    Digits::pow(&mut base, rhs)
+  }
+}
+
+impl Pow<usize> for Digits {
+  type Output = Digits;
+  fn pow(self, rhs: usize) -> Digits {
+    let power = self.gen(rhs as u64);
+    let mut base = self;
+    Digits::pow(&mut base, power)
+  }
+}
+
+impl ToPrimitive for Digits {
+  fn to_u64(&self) -> Option<u64> {
+    let base = self.mapping.base;
+    self.as_mapping_vec().iter().try_fold(0u64, |acc, &place| {
+      acc.checked_mul(base).and_then(|v| v.checked_add(place))
+    })
+  }
+
+  fn to_i64(&self) -> Option<i64> {
+    self.to_u64().and_then(|v| v.to_i64())
+  }
+}
+
+impl FromPrimitive for Digits {
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(Digits::from((radices::decimal_base(), n)))
+  }
+
+  fn from_i64(n: i64) -> Option<Self> {
+    if n < 0 { return None; }
+    Self::from_u64(n as u64)
+  }
+}
+
+/// One of the standard alphabets in `radices`, chosen by type for `Fixed`.
+pub trait FixedRadix {
+  /// The alphabet's character mapping.
+  fn mapping() -> BaseCustom<char>;
+}
+
+/// The `radices::binary_base` alphabet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Binary;
+
+/// The `radices::octal_base` alphabet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Octal;
+
+/// The `radices::decimal_base` alphabet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal;
+
+/// The `radices::hex_base` alphabet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex;
+
+/// The `radices::hexl_base` alphabet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hexl;
+
+impl FixedRadix for Binary {
+  fn mapping() -> BaseCustom<char> { radices::binary_base() }
+}
+
+impl FixedRadix for Octal {
+  fn mapping() -> BaseCustom<char> { radices::octal_base() }
+}
+
+impl FixedRadix for Decimal {
+  fn mapping() -> BaseCustom<char> { radices::decimal_base() }
+}
+
+impl FixedRadix for Hex {
+  fn mapping() -> BaseCustom<char> { radices::hex_base() }
+}
+
+impl FixedRadix for Hexl {
+  fn mapping() -> BaseCustom<char> { radices::hexl_base() }
+}
+
+/// A `Digits` value zero filled to exactly `W` places of a standard alphabet, such
+/// as a four place PIN or a 32 place hex key.  Its bounds are `W` zeros and `W` of
+/// the alphabet's last character.
+///
+/// # Example
+///
+/// ```
+/// extern crate num_traits;
+/// use digits::prelude::*;
+/// use digits::numeric::{Fixed,Decimal};
+/// use num_traits::Bounded;
+///
+/// type Pin = Fixed<Decimal, 4>;
+///
+/// assert_eq!(Pin::min_value().digits().to_s(), "0000");
+/// assert_eq!(Pin::max_value().digits().to_s(), "9999");
+///
+/// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+/// let pin = Pin::new(Digits::new(base10, "42".to_string())).unwrap();
+/// assert_eq!(pin.digits().to_s(), "0042");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<R, const W: usize> {
+  value: Digits,
+  radix: PhantomData<R>,
+}
+
+impl<R: FixedRadix, const W: usize> Fixed<R, W> {
+  /// Wraps a value, zero filling it to `W` places.
+  ///
+  /// Returns an `Err(&'static str)` Result if the value uses another mapping or needs
+  /// more than `W` places without its zero padding.
+  pub fn new(value: Digits) -> Result<Self, &'static str> {
+    if *value.mapping != R::mapping() { return Err("Incompatible numeric base mappings!"); }
+    let mut value = value;
+    value.zero_trim();
+    if value.length() > W { return Err("Value is wider than the fixed width!"); }
+    value.zero_fill(W);
+    Ok(Fixed { value, radix: PhantomData })
+  }
+
+  /// The value, `W` places long.
+  pub fn digits(&self) -> &Digits {
+    &self.value
+  }
+
+  /// Unwraps the value, `W` places long.
+  pub fn into_digits(self) -> Digits {
+    self.value
+  }
+
+  fn filled(place: u64) -> Self {
+    Fixed { value: Digits::from_places(Arc::new(R::mapping()), &vec![place; W]), radix: PhantomData }
+  }
+}
+
+impl<R: FixedRadix, const W: usize> Bounded for Fixed<R, W> {
+  fn min_value() -> Self {
+    Fixed::filled(0)
+  }
+
+  fn max_value() -> Self {
+    Fixed::filled(R::mapping().base - 1)
+  }
+}
@@ -0,0 +1,183 @@
+//! Rayon parallel iterators over `DigitsRange` and `Mask` enumerations.
+//!
+//! _Only available with the `rayon` feature enabled._
+//!
+//! Work is split at the numeric midpoint of what remains, so keyspaces far beyond
+//! `u64` divide as evenly as small ones.
+//!
+//! # Example
+//!
+//! ```
+//! extern crate digits;
+//! extern crate rayon;
+//! use digits::prelude::*;
+//! use digits::range::DigitsRange;
+//! use rayon::prelude::*;
+//!
+//! # fn main() {
+//! let base36 = BaseCustom::<char>::new("0123456789abcdefghijklmnopqrstuvwxyz".chars().collect());
+//! let start = Digits::new(base36.clone(), "000".to_string());
+//! let end = Digits::new(base36, "zzz".to_string());
+//! let range = DigitsRange::new(start, end).unwrap();
+//!
+//! let found = range.par_iter().find_any(|d| d.to_s() == "k3y");
+//! assert_eq!(found.unwrap().to_s(), "k3y");
+//! # }
+//! ```
+use rayon::iter::plumbing::{bridge_unindexed, Folder, UnindexedConsumer, UnindexedProducer};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use mask::Mask;
+use range::DigitsRange;
+use Digits;
+
+/// A parallel iterator over the values of a `DigitsRange`.
+#[derive(Clone, Debug)]
+pub struct ParDigitsRange {
+  range: DigitsRange,
+}
+
+impl IntoParallelIterator for DigitsRange {
+  type Iter = ParDigitsRange;
+  type Item = Digits;
+
+  fn into_par_iter(self) -> ParDigitsRange {
+    ParDigitsRange { range: self }
+  }
+}
+
+impl IntoParallelIterator for &DigitsRange {
+  type Iter = ParDigitsRange;
+  type Item = Digits;
+
+  fn into_par_iter(self) -> ParDigitsRange {
+    ParDigitsRange { range: self.clone() }
+  }
+}
+
+impl ParallelIterator for ParDigitsRange {
+  type Item = Digits;
+
+  fn drive_unindexed<C>(self, consumer: C) -> C::Result
+  where C: UnindexedConsumer<Digits> {
+    bridge_unindexed(self, consumer)
+  }
+}
+
+impl UnindexedProducer for ParDigitsRange {
+  type Item = Digits;
+
+  fn split(self) -> (Self, Option<Self>) {
+    let start = match self.range.peek() {
+      Some(start) => start.clone(),
+      None => return (self, None),
+    };
+    let end = self.range.end().clone();
+    let gap = end.clone() - start.clone();
+    if gap.is_zero() { return (self, None); }
+
+    let mut mid = start.clone();
+    mid.add_in_place(&(gap / start.gen(2_u64)));
+    let mut right_start = mid.clone();
+    right_start.succ_in_place();
+    (
+      ParDigitsRange { range: DigitsRange::new(start, mid).unwrap() },
+      Some(ParDigitsRange { range: DigitsRange::new(right_start, end).unwrap() }),
+    )
+  }
+
+  fn fold_with<F>(self, folder: F) -> F
+  where F: Folder<Digits> {
+    folder.consume_iter(self.range)
+  }
+}
+
+/// A parallel iterator over the candidates of a `Mask`.
+#[derive(Clone, Debug)]
+pub struct ParMask {
+  mask: Mask,
+  // Inclusive bounds as indices into each position's characters.
+  first: Vec<usize>,
+  last: Vec<usize>,
+}
+
+impl IntoParallelIterator for Mask {
+  type Iter = ParMask;
+  type Item = String;
+
+  fn into_par_iter(self) -> ParMask {
+    let first = vec![0; self.positions().len()];
+    let last = self.positions().iter().map(|set| set.len() - 1).collect();
+    ParMask { mask: self, first, last }
+  }
+}
+
+impl IntoParallelIterator for &Mask {
+  type Iter = ParMask;
+  type Item = String;
+
+  fn into_par_iter(self) -> ParMask {
+    self.clone().into_par_iter()
+  }
+}
+
+impl ParallelIterator for ParMask {
+  type Item = String;
+
+  fn drive_unindexed<C>(self, consumer: C) -> C::Result
+  where C: UnindexedConsumer<String> {
+    bridge_unindexed(self, consumer)
+  }
+}
+
+impl UnindexedProducer for ParMask {
+  type Item = String;
+
+  fn split(self) -> (Self, Option<Self>) {
+    if self.first == self.last { return (self, None); }
+    let radices: Vec<usize> = self.mask.positions().iter().map(|set| set.len()).collect();
+
+    // Mixed radix arithmetic on the position indices: take the gap between first and
+    // last, halve it by long division from the most significant position, then add
+    // the half back onto first.
+    let mut half = vec![0; radices.len()];
+    let mut borrow = 0;
+    let mut remainder = 0;
+    for i in (0..radices.len()).rev() {
+      let mut place = self.last[i] as isize - self.first[i] as isize - borrow;
+      borrow = if place < 0 { place += radices[i] as isize; 1 } else { 0 };
+      half[i] = place as usize;
+    }
+    for (place, &radix) in half.iter_mut().zip(&radices) {
+      let current = remainder * radix + *place;
+      *place = current / 2;
+      remainder = current % 2;
+    }
+    let mut mid = self.first.clone();
+    let mut carry = 0;
+    for i in (0..radices.len()).rev() {
+      let sum = mid[i] + half[i] + carry;
+      mid[i] = sum % radices[i];
+      carry = sum / radices[i];
+    }
+    let mut right_first = mid.clone();
+    for i in (0..radices.len()).rev() {
+      right_first[i] += 1;
+      if right_first[i] < radices[i] { break; }
+      right_first[i] = 0;
+    }
+
+    let right = ParMask { mask: self.mask.clone(), first: right_first, last: self.last };
+    (ParMask { mask: self.mask, first: self.first, last: mid }, Some(right))
+  }
+
+  fn fold_with<F>(self, folder: F) -> F
+  where F: Folder<String> {
+    let mut iter = self.mask.iter_from(&self.first).unwrap();
+    let last = self.last;
+    let bounded = ::std::iter::from_fn(move || {
+      if iter.position().is_none_or(|p| p > &last[..]) { return None; }
+      iter.next()
+    });
+    folder.consume_iter(bounded)
+  }
+}
@@ -0,0 +1,135 @@
+//! Splits a keyspace between worker threads a chunk at a time.
+//!
+//! Rather than giving each thread a fixed share up front, a `Dispenser` hands out the
+//! next fixed-size chunk whenever a worker asks for one, so faster workers simply
+//! take more chunks.
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use range::DigitsRange;
+use Digits;
+
+/// A thread-safe source of consecutive chunks of a `DigitsRange`.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::parallel::Dispenser;
+/// use digits::range::DigitsRange;
+///
+/// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+/// let start = Digits::new(base10.clone(), "00".to_string());
+/// let end = Digits::new(base10, "24".to_string());
+///
+/// let dispenser = Dispenser::new(DigitsRange::new(start, end).unwrap(), 10);
+/// let chunks: Vec<(String, String)> = std::iter::from_fn(|| dispenser.next_chunk())
+///   .map(|chunk| (chunk.peek().unwrap().to_s(), chunk.end().to_s()))
+///   .collect();
+///
+/// assert_eq!(chunks, vec![
+///   ("00".to_string(), "09".to_string()),
+///   ("10".to_string(), "19".to_string()),
+///   ("20".to_string(), "24".to_string()),
+/// ]);
+/// ```
+#[derive(Debug)]
+pub struct Dispenser {
+  next: Mutex<Option<Digits>>,
+  end: Digits,
+  step: Digits,
+  stopped: AtomicBool,
+}
+
+impl Dispenser {
+  /// Creates a dispenser over the remaining values of `range` handing out chunks of
+  /// `chunk_size` values.
+  ///
+  /// _This will panic if `chunk_size` is zero._
+  pub fn new(range: DigitsRange, chunk_size: u64) -> Dispenser {
+    assert!(chunk_size > 0, "A chunk needs at least one value!");
+    let end = range.end().clone();
+    let step = end.gen(chunk_size - 1);
+    Dispenser { next: Mutex::new(range.peek().cloned()), end, step, stopped: AtomicBool::new(false) }
+  }
+
+  /// Takes the next chunk, or `None` once the range is used up or `stop` was called.
+  pub fn next_chunk(&self) -> Option<DigitsRange> {
+    if self.is_stopped() { return None; }
+    let mut next = self.next.lock().unwrap();
+    let start = next.take()?;
+    let mut last = start.clone();
+    last.add_in_place(&self.step);
+    if last >= self.end {
+      last = self.end.clone();
+    } else {
+      let mut following = last.clone();
+      following.succ_in_place();
+      *next = Some(following);
+    }
+    Some(DigitsRange::new(start, last).unwrap())
+  }
+
+  /// Stops handing out chunks.  Workers should also check `is_stopped` within a chunk.
+  pub fn stop(&self) {
+    self.stopped.store(true, AtomicOrdering::Relaxed);
+  }
+
+  /// Whether `stop` has been called.
+  pub fn is_stopped(&self) -> bool {
+    self.stopped.load(AtomicOrdering::Relaxed)
+  }
+}
+
+/// Calls `f` for every value of `range` across `threads` worker threads, which take
+/// `chunk_size` values at a time from a shared `Dispenser`.  When `f` returns `true` for
+/// a value every worker stops early and that value is returned.  Which match is
+/// returned when several are found at once is not specified.
+///
+/// With `threads` set to zero one worker is started per available CPU.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::parallel::par_for_each;
+/// use digits::range::DigitsRange;
+///
+/// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+/// let start = Digits::new(base16.clone(), "0000".to_string());
+/// let end = Digits::new(base16, "FFFF".to_string());
+///
+/// let found = par_for_each(DigitsRange::new(start, end).unwrap(), 256, 4, |d| d.to_s() == "BEEF");
+/// assert_eq!(found.unwrap().to_s(), "BEEF");
+/// ```
+pub fn par_for_each<F>(range: DigitsRange, chunk_size: u64, threads: usize, f: F) -> Option<Digits>
+where F: Fn(&Digits) -> bool + Sync {
+  let threads = match threads {
+    0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+    n => n,
+  };
+  let dispenser = Dispenser::new(range, chunk_size);
+  let (found, matches) = mpsc::channel();
+
+  thread::scope(|scope| {
+    for _ in 0..threads {
+      let found = found.clone();
+      let (dispenser, f) = (&dispenser, &f);
+      scope.spawn(move || {
+        while let Some(chunk) = dispenser.next_chunk() {
+          for value in chunk {
+            if dispenser.is_stopped() { return; }
+            if f(&value) {
+              dispenser.stop();
+              let _ = found.send(value);
+              return;
+            }
+          }
+        }
+      });
+    }
+  });
+  drop(found);
+  matches.into_iter().next()
+}
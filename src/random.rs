@@ -0,0 +1,139 @@
+//! Uniform random sampling of `Digits` values.
+//!
+//! Randomness comes from any `RandomSource`, so tests can plug in the deterministic
+//! `SplitMix64` while production code wraps a cryptographic generator.  Closures
+//! returning `u64` are sources too.
+use std::cmp::Ordering;
+use internal::places;
+use range::DigitsRange;
+use Digits;
+
+/// A source of uniformly distributed 64 bit values.
+pub trait RandomSource {
+  /// Returns the next random value.
+  fn next_u64(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> RandomSource for F {
+  fn next_u64(&mut self) -> u64 {
+    self()
+  }
+}
+
+/// A small, fast and deterministic generator.  It is _not_ suitable for anything
+/// security related; use it for reproducible tests and simulations.
+///
+/// # Example
+///
+/// ```
+/// use digits::random::{RandomSource, SplitMix64};
+///
+/// let mut a = SplitMix64::new(7);
+/// let mut b = SplitMix64::new(7);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+// Not `Copy`, so a generator is never duplicated by accident and its values repeated.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+  state: u64,
+}
+
+impl SplitMix64 {
+  /// Creates a generator from a seed.
+  pub fn new(seed: u64) -> SplitMix64 {
+    SplitMix64 { state: seed }
+  }
+}
+
+impl RandomSource for SplitMix64 {
+  fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+}
+
+// A uniform value in `0..n` by rejecting the uneven tail of the u64 range.
+fn below<R: RandomSource + ?Sized>(rng: &mut R, n: u64) -> u64 {
+  let zone = u64::MAX - u64::MAX % n;
+  loop {
+    let x = rng.next_u64();
+    if x < zone { return x % n; }
+  }
+}
+
+impl Digits {
+  /// A uniformly distributed value from zero up to but not including `bound`, in the
+  /// bound's mapping and without zero padding.
+  ///
+  /// Places are drawn at random with the leading one capped by the bound's leading
+  /// place, and the whole value is rejected and redrawn if it is not below the bound.
+  /// At least half of all draws are accepted, whatever the length of the bound.
+  ///
+  /// Returns an `Err(&'static str)` Result if the bound is zero.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use digits::random::SplitMix64;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let bound = Digits::new(base10, "250".to_string());
+  /// let mut rng = SplitMix64::new(42);
+  ///
+  /// let value = Digits::random_below(&bound, &mut rng).unwrap();
+  /// assert!(value < bound);
+  /// ```
+  pub fn random_below<R: RandomSource + ?Sized>(bound: &Digits, rng: &mut R) -> Result<Digits, &'static str> {
+    let places = bound.as_mapping_vec();
+    let start = places.iter().position(|&x| x != 0).ok_or("Cannot sample below zero!")?;
+    let places = &places[start..];
+    let base = bound.mapping.base;
+    let mut value = vec![0; places.len()];
+    loop {
+      value[0] = below(rng, places[0] + 1);
+      for place in value.iter_mut().skip(1) {
+        *place = below(rng, base);
+      }
+      if value.as_slice().cmp(places) == Ordering::Less { break; }
+    }
+    let value = match places::trimmed(&value) {
+      [] => &[0][..],
+      trimmed => trimmed,
+    };
+    Ok(Digits::from_places(bound.mapping.clone(), value))
+  }
+
+  /// A uniformly distributed value from the remaining values of `range`, keeping the
+  /// zero padding of the range's next value.
+  ///
+  /// Returns an `Err(&'static str)` Result if the range is empty.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use digits::random::SplitMix64;
+  /// use digits::range::DigitsRange;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let start = Digits::new(base10.clone(), "0100".to_string());
+  /// let end = Digits::new(base10, "0199".to_string());
+  /// let range = DigitsRange::new(start.clone(), end.clone()).unwrap();
+  ///
+  /// let value = Digits::random_in(&range, &mut SplitMix64::new(1)).unwrap();
+  /// assert!(value >= start && value <= end);
+  /// assert_eq!(value.length(), 4);
+  /// ```
+  pub fn random_in<R: RandomSource + ?Sized>(range: &DigitsRange, rng: &mut R) -> Result<Digits, &'static str> {
+    let start = range.peek().ok_or("Cannot sample from an empty range!")?;
+    let offset = Digits::random_below(&range.remaining(), rng)?;
+    let mut result = start.clone();
+    result.add_in_place(&offset);
+    Ok(result)
+  }
+}
@@ -0,0 +1,88 @@
+//! Inclusive ranges of `Digits` values for enumerating a keyspace.
+use std::cmp::Ordering;
+use Digits;
+
+/// An iterator over every value from a start `Digits` up to and including an end `Digits`.
+///
+/// Values are stepped with `succ` so they keep the zero padding of the start value.
+/// The end is compared by value, so its own padding does not matter.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::range::DigitsRange;
+///
+/// let base3 = BaseCustom::<char>::new("abc".chars().collect());
+/// let start = Digits::new(base3.clone(), "ab".to_string());
+/// let end = Digits::new(base3, "bb".to_string());
+///
+/// let values: Vec<String> = DigitsRange::new(start, end).unwrap().map(|d| d.to_s()).collect();
+/// assert_eq!(values, vec!["ab", "ac", "ba", "bb"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DigitsRange {
+  next: Option<Digits>,
+  end: Digits,
+}
+
+impl DigitsRange {
+  /// Creates a range from `start` through `end`.  The range is empty if `start` is
+  /// greater than `end`.
+  ///
+  /// Returns an `Err(&'static str)` Result if the two values use different mappings.
+  pub fn new(start: Digits, end: Digits) -> Result<Self, &'static str> {
+    if !start.is_compat(&end) { return Err("Incompatible numeric base mappings!"); }
+    let next = if start.cmp_digits(&end) == Ordering::Greater { None } else { Some(start) };
+    Ok(DigitsRange { next, end })
+  }
+
+  /// The last value of the range.
+  pub fn end(&self) -> &Digits {
+    &self.end
+  }
+
+  /// The value the next call to `next` will yield, or `None` once the range is done.
+  pub fn peek(&self) -> Option<&Digits> {
+    self.next.as_ref()
+  }
+
+  /// How many values are left to yield, as a `Digits` in the range's own mapping.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  /// use digits::range::DigitsRange;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let start = Digits::new(base10.clone(), "0005".to_string());
+  /// let end = Digits::new(base10, "120".to_string());
+  ///
+  /// assert_eq!(DigitsRange::new(start, end).unwrap().remaining().to_s(), "116");
+  /// ```
+  pub fn remaining(&self) -> Digits {
+    match self.next {
+      Some(ref next) => {
+        let mut count = self.end.clone() - next.clone();
+        count.succ_in_place().zero_trim();
+        count
+      },
+      None => self.end.zero(),
+    }
+  }
+}
+
+impl Iterator for DigitsRange {
+  type Item = Digits;
+
+  fn next(&mut self) -> Option<Digits> {
+    let current = self.next.take()?;
+    if current.cmp_digits(&self.end) == Ordering::Less {
+      let mut following = current.clone();
+      following.succ_in_place();
+      self.next = Some(following);
+    }
+    Some(current)
+  }
+}
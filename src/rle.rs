@@ -0,0 +1,118 @@
+//! Run-length encoding of `Digits` values.
+//!
+//! A run is a mapping index and how many times it repeats, most significant run
+//! first.  Long padded or sparse values shrink to a handful of runs, which can be
+//! stored and later expanded back into the full value.
+//!
+//! Runs also have a text form: runs separated by whitespace, each a character
+//! optionally followed by `×` (or `*`) and a decimal count, as in `a×500 b c×3`.
+//! Whitespace characters cannot be written this way.
+use std::sync::Arc;
+use {BaseCustom, Digits};
+
+impl Digits {
+  /// Builds a value from runs of mapping indices, as produced by `to_rle`.
+  ///
+  /// Returns an `Err(&'static str)` Result if there are no runs, a run is empty, an
+  /// index is outside of the mapping, or the runs add up to more places than can be
+  /// held in memory.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::from_rle(base10, &[(1, 1), (0, 6)]).unwrap();
+  ///
+  /// assert_eq!(num.to_s(), "1000000");
+  /// ```
+  pub fn from_rle(mapping: BaseCustom<char>, runs: &[(u64, usize)]) -> Result<Digits, &'static str> {
+    if runs.is_empty() { return Err("A run-length encoding needs at least one run!"); }
+    let mut total: usize = 0;
+    for &(index, length) in runs {
+      if length == 0 { return Err("A run needs a length of at least one!"); }
+      if index >= mapping.base { return Err("Character mapping out of range!"); }
+      total = total.checked_add(length).ok_or("Run-length encoding is too long to expand!")?;
+    }
+    // The counts are untrusted, so memory is only taken run by run and a failed
+    // allocation is an error rather than an abort.
+    let mut places = vec![];
+    for &(index, length) in runs {
+      places.try_reserve(length).map_err(|_| "Run-length encoding is too long to expand!")?;
+      places.extend(std::iter::repeat_n(index, length));
+    }
+    Ok(Digits::from_places(Arc::new(mapping), &places))
+  }
+
+  /// Builds a value from the text form of its runs, such as `a×500 b c×3`.
+  ///
+  /// Returns an `Err(&'static str)` Result if the text is empty, a character is not in
+  /// the mapping, a count is missing, malformed or zero, or the counts are too large to
+  /// expand.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  /// let num = Digits::from_rle_str(base3, "a×2 b c*3").unwrap();
+  ///
+  /// assert_eq!(num.to_s(), "aabccc");
+  /// assert_eq!(num.to_rle_string(), "a×2 b c×3");
+  /// ```
+  pub fn from_rle_str(mapping: BaseCustom<char>, description: &str) -> Result<Digits, &'static str> {
+    let mut runs = vec![];
+    for token in description.split_whitespace() {
+      let mut chars = token.chars();
+      let c = chars.next().unwrap();
+      let index = (0..mapping.base as usize).position(|i| mapping.char(i) == Some(c))
+        .ok_or("Character is not in the mapping!")?;
+      let rest = chars.as_str();
+      let length = match rest.chars().next() {
+        None => 1,
+        Some('×') | Some('*') => {
+          let count = &rest[rest.chars().next().unwrap().len_utf8()..];
+          count.parse::<usize>().map_err(|_| "Malformed run count!")?
+        },
+        Some(_) => return Err("A run is one character and an optional count!"),
+      };
+      runs.push((index as u64, length));
+    }
+    Digits::from_rle(mapping, &runs)
+  }
+
+  /// The runs of repeated mapping indices in this value, including its zero padding.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::new(base10, "0007000".to_string());
+  ///
+  /// assert_eq!(num.to_rle(), vec![(0, 3), (7, 1), (0, 3)]);
+  /// ```
+  pub fn to_rle(&self) -> Vec<(u64, usize)> {
+    let mut runs: Vec<(u64, usize)> = vec![];
+    for node in self.nodes() {
+      match runs.last_mut() {
+        Some(&mut (index, ref mut length)) if index == node.digit => *length += 1,
+        _ => runs.push((node.digit, 1)),
+      }
+    }
+    runs.reverse();
+    runs
+  }
+
+  /// The text form of this value's runs, with counts only on runs longer than one.
+  pub fn to_rle_string(&self) -> String {
+    let runs: Vec<String> = self.to_rle().iter().map(|&(index, length)| {
+      let c = self.mapping.char(index as usize).unwrap();
+      if length == 1 { c.to_string() } else { format!("{}×{}", c, length) }
+    }).collect();
+    runs.join(" ")
+  }
+}
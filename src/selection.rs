@@ -0,0 +1,306 @@
+//! Permutations and combinations drawn from an alphabet without repetition.
+//!
+//! Where `succ` counts through every string with repeats allowed, a `Selections`
+//! enumerates strings of `k` distinct characters: ordered k-permutations, or
+//! k-combinations with their characters in alphabet order.  Both are listed in
+//! lexicographic order of the alphabet.
+//!
+//! Each selection has a rank, its position in that order, as a decimal `Digits`.
+//! `ranks` gives every rank as a `DigitsRange`, so a keyspace of selections can be
+//! split with the same tools as any other range and each chunk enumerated with
+//! `iter_range`.
+use std::cmp::Ordering;
+use std::sync::Arc;
+use internal::places;
+use radices::decimal_base;
+use range::DigitsRange;
+use {BaseCustom, Digits};
+
+// Ranks are worked out as decimal place vectors.
+const DECIMAL: u64 = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+  Permutations,
+  Combinations,
+}
+
+/// The k-permutations or k-combinations of an alphabet.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::parallel::Dispenser;
+/// use digits::selection::Selections;
+///
+/// let letters = BaseCustom::<char>::new("abcd".chars().collect());
+/// let pairs = Selections::permutations(letters, 2).unwrap();
+///
+/// assert_eq!(pairs.count().to_s(), "12");
+/// let first: Vec<String> = pairs.iter().take(4).map(|d| d.to_s()).collect();
+/// assert_eq!(first, vec!["ab", "ac", "ad", "ba"]);
+///
+/// let dispenser = Dispenser::new(pairs.ranks(), 5);
+/// let chunk = dispenser.next_chunk().unwrap();
+/// assert_eq!(pairs.iter_range(chunk).unwrap().last().unwrap().to_s(), "bc");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Selections {
+  kind: Kind,
+  mapping: Arc<BaseCustom<char>>,
+  k: usize,
+}
+
+impl Selections {
+  /// The ordered selections of `k` distinct characters from `mapping`.
+  ///
+  /// Returns an `Err(&'static str)` Result if `k` is zero or larger than the alphabet.
+  pub fn permutations(mapping: BaseCustom<char>, k: usize) -> Result<Selections, &'static str> {
+    Selections::from_kind(Kind::Permutations, mapping, k)
+  }
+
+  /// The unordered selections of `k` distinct characters from `mapping`, each written
+  /// with its characters in alphabet order.
+  ///
+  /// Returns an `Err(&'static str)` Result if `k` is zero or larger than the alphabet.
+  pub fn combinations(mapping: BaseCustom<char>, k: usize) -> Result<Selections, &'static str> {
+    Selections::from_kind(Kind::Combinations, mapping, k)
+  }
+
+  fn from_kind(kind: Kind, mapping: BaseCustom<char>, k: usize) -> Result<Selections, &'static str> {
+    if k == 0 || k as u64 > mapping.base {
+      return Err("A selection needs between one and the alphabet's size of characters!");
+    }
+    Ok(Selections { kind, mapping: Arc::new(mapping), k })
+  }
+
+  /// How many selections there are, in decimal.
+  pub fn count(&self) -> Digits {
+    decimal(&self.total())
+  }
+
+  /// The rank of the given selection, in decimal.
+  ///
+  /// Returns an `Err(&'static str)` Result if the value uses another mapping, is not
+  /// `k` places long including zero padding, repeats a character, or for
+  /// combinations, does not have its characters in alphabet order.
+  pub fn rank(&self, value: &Digits) -> Result<Digits, &'static str> {
+    if value.mapping != self.mapping { return Err("Incompatible numeric base mappings!"); }
+    let chosen = value.as_mapping_vec();
+    if chosen.len() != self.k { return Err("A selection must be exactly k places long!"); }
+    let mut seen = vec![false; self.n()];
+    for (i, &place) in chosen.iter().enumerate() {
+      if seen[place as usize] { return Err("A selection may not repeat a character!"); }
+      if self.kind == Kind::Combinations && i > 0 && place < chosen[i - 1] {
+        return Err("A combination must have its characters in alphabet order!");
+      }
+      seen[place as usize] = true;
+    }
+
+    let n = self.n();
+    let mut rank = vec![0];
+    match self.kind {
+      Kind::Permutations => {
+        let mut used = vec![false; n];
+        for (i, &place) in chosen.iter().enumerate() {
+          let smaller = used[..place as usize].iter().filter(|&&u| !u).count() as u64;
+          let skipped = places::mul(&small(smaller), &permutations(n - 1 - i, self.k - 1 - i), DECIMAL);
+          rank = places::add(&rank, &skipped, DECIMAL);
+          used[place as usize] = true;
+        }
+      },
+      Kind::Combinations => {
+        let mut from = 0;
+        for (i, &place) in chosen.iter().enumerate() {
+          for v in from..place as usize {
+            rank = places::add(&rank, &combinations(n - 1 - v, self.k - 1 - i), DECIMAL);
+          }
+          from = place as usize + 1;
+        }
+      },
+    }
+    Ok(decimal(&rank))
+  }
+
+  /// The selection with the given decimal rank.
+  ///
+  /// Returns an `Err(&'static str)` Result if the rank is not decimal or not below
+  /// `count`.
+  pub fn unrank(&self, rank: &Digits) -> Result<Digits, &'static str> {
+    let chosen = self.unrank_places(rank)?;
+    Ok(Digits::from_places(self.mapping.clone(), &chosen))
+  }
+
+  /// Every rank from zero to one below `count`.
+  pub fn ranks(&self) -> DigitsRange {
+    let last = places::sub(&self.total(), &[1], DECIMAL).unwrap();
+    DigitsRange::new(Digits::new_zero(decimal_base()), decimal(&last)).unwrap()
+  }
+
+  /// Iterates over every selection in order.
+  pub fn iter(&self) -> SelectionIter {
+    self.iter_range(self.ranks()).unwrap()
+  }
+
+  /// Iterates over the selections whose ranks remain in the given range, such as a
+  /// chunk handed out by a `Dispenser`.
+  ///
+  /// Returns an `Err(&'static str)` Result if the range is not decimal or goes past
+  /// the last rank.
+  pub fn iter_range(&self, ranks: DigitsRange) -> Result<SelectionIter, &'static str> {
+    if *ranks.end().mapping != decimal_base() { return Err("Ranks must be decimal!"); }
+    let current = match ranks.peek() {
+      Some(start) => {
+        if places::cmp(&ranks.end().as_mapping_vec(), &self.total()) != Ordering::Less {
+          return Err("Rank is past the last selection!");
+        }
+        self.unrank_places(start)?
+      },
+      None => vec![],
+    };
+    Ok(SelectionIter { selections: self.clone(), ranks, current })
+  }
+
+  fn n(&self) -> usize {
+    self.mapping.base as usize
+  }
+
+  fn total(&self) -> Vec<u64> {
+    match self.kind {
+      Kind::Permutations => permutations(self.n(), self.k),
+      Kind::Combinations => combinations(self.n(), self.k),
+    }
+  }
+
+  fn unrank_places(&self, rank: &Digits) -> Result<Vec<u64>, &'static str> {
+    if *rank.mapping != decimal_base() { return Err("Ranks must be decimal!"); }
+    let mut rest = rank.as_mapping_vec();
+    if places::cmp(&rest, &self.total()) != Ordering::Less {
+      return Err("Rank is past the last selection!");
+    }
+    let n = self.n();
+    let mut chosen = Vec::with_capacity(self.k);
+    match self.kind {
+      Kind::Permutations => {
+        let mut used = vec![false; n];
+        for i in 0..self.k {
+          let (skipped, remainder) = places::div_rem(&rest, &permutations(n - 1 - i, self.k - 1 - i), DECIMAL).unwrap();
+          rest = remainder;
+          let skipped = skipped.iter().fold(0, |acc, &p| acc * DECIMAL + p) as usize;
+          let place = (0..n).filter(|&x| !used[x]).nth(skipped).unwrap();
+          used[place] = true;
+          chosen.push(place as u64);
+        }
+      },
+      Kind::Combinations => {
+        let mut v = 0;
+        for i in 0..self.k {
+          loop {
+            let block = combinations(n - 1 - v, self.k - 1 - i);
+            match places::sub(&rest, &block, DECIMAL) {
+              Some(remainder) => { rest = remainder; v += 1; },
+              None => break,
+            }
+          }
+          chosen.push(v as u64);
+          v += 1;
+        }
+      },
+    }
+    Ok(chosen)
+  }
+}
+
+fn small(n: u64) -> Vec<u64> {
+  n.to_string().bytes().map(|b| u64::from(b - b'0')).collect()
+}
+
+fn decimal(places: &[u64]) -> Digits {
+  let trimmed = match places::trimmed(places) {
+    [] => &[0][..],
+    trimmed => trimmed,
+  };
+  Digits::from_places(Arc::new(decimal_base()), trimmed)
+}
+
+// n! / (n - k)!
+fn permutations(n: usize, k: usize) -> Vec<u64> {
+  (n - k + 1..=n).fold(vec![1], |acc, x| places::mul(&acc, &small(x as u64), DECIMAL))
+}
+
+// n! / (k! (n - k)!), exact at every step as each partial product is itself a binomial.
+fn combinations(n: usize, k: usize) -> Vec<u64> {
+  (0..k).fold(vec![1], |acc, i| {
+    let product = places::mul(&acc, &small((n - i) as u64), DECIMAL);
+    places::div_rem(&product, &small(i as u64 + 1), DECIMAL).unwrap().0
+  })
+}
+
+// Steps `chosen` to the next k-permutation of `0..n`, returning false after the last.
+fn next_permutation(chosen: &mut [u64], n: usize) -> bool {
+  let mut used = vec![false; n];
+  for &place in chosen.iter() { used[place as usize] = true; }
+  for i in (0..chosen.len()).rev() {
+    used[chosen[i] as usize] = false;
+    if let Some(larger) = (chosen[i] as usize + 1..n).find(|&x| !used[x]) {
+      chosen[i] = larger as u64;
+      used[larger] = true;
+      let mut free = (0..n).filter(|&x| !used[x]);
+      for place in chosen[i + 1..].iter_mut() {
+        *place = free.next().unwrap() as u64;
+      }
+      return true;
+    }
+  }
+  false
+}
+
+// Steps `chosen` to the next k-combination of `0..n`, returning false after the last.
+fn next_combination(chosen: &mut [u64], n: usize) -> bool {
+  let k = chosen.len();
+  for i in (0..k).rev() {
+    if (chosen[i] as usize) < n - k + i {
+      chosen[i] += 1;
+      for j in i + 1..k {
+        chosen[j] = chosen[j - 1] + 1;
+      }
+      return true;
+    }
+  }
+  false
+}
+
+/// An iterator over selections in rank order, created by `Selections::iter` or
+/// `Selections::iter_range`.
+#[derive(Clone, Debug)]
+pub struct SelectionIter {
+  selections: Selections,
+  ranks: DigitsRange,
+  // The selection for the next rank.
+  current: Vec<u64>,
+}
+
+impl SelectionIter {
+  /// The rank of the selection `next` will yield, or `None` once the ranks are done.
+  pub fn position(&self) -> Option<&Digits> {
+    self.ranks.peek()
+  }
+}
+
+impl Iterator for SelectionIter {
+  type Item = Digits;
+
+  fn next(&mut self) -> Option<Digits> {
+    self.ranks.next()?;
+    let value = Digits::from_places(self.selections.mapping.clone(), &self.current);
+    if self.ranks.peek().is_some() {
+      let n = self.selections.n();
+      match self.selections.kind {
+        Kind::Permutations => next_permutation(&mut self.current, n),
+        Kind::Combinations => next_combination(&mut self.current, n),
+      };
+    }
+    Some(value)
+  }
+}
@@ -0,0 +1,176 @@
+//! Serde `Serialize` and `Deserialize` support for `Digits`.
+//!
+//! _Only available with the `serde` feature enabled._
+//!
+//! By default a `Digits` value is written as its alphabet along with its value string,
+//! zero padding included.
+//!
+//! ```text
+//! {"alphabet":"0123456789","value":"0042"}
+//! ```
+//!
+//! The `compact` module writes the value as its vector of character positions instead.
+//! Deserializing a `Digits` accepts either form.
+//!
+//! ```text
+//! {"alphabet":"0123456789","places":[0,0,4,2]}
+//! ```
+use std::fmt;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::{self, Visitor};
+use super::{alphabet, BaseCustom, Digits};
+
+/// A serializable descriptor of a `BaseCustom<char>` character mapping.
+///
+/// This is written as a string of every character in the mapping, ordered from zero.
+/// On load the string must hold between 2 and 255 characters without any repeats.
+///
+/// # Example
+///
+/// ```
+/// extern crate digits;
+/// extern crate serde_json;
+/// use digits::prelude::*;
+/// use digits::serialization::Alphabet;
+///
+/// # fn main() {
+/// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+/// let json = serde_json::to_string(&Alphabet::from(base16.clone())).unwrap();
+///
+/// assert_eq!(json, "\"0123456789ABCDEF\"");
+///
+/// let alphabet: Alphabet = serde_json::from_str(&json).unwrap();
+/// assert!(alphabet.mapping() == &base16);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Alphabet(BaseCustom<char>);
+
+impl Alphabet {
+  /// Creates an `Alphabet` from a string of characters ordered from zero.
+  ///
+  /// Returns an `Err(&'static str)` Result if there are fewer than 2 or more than
+  /// 255 characters, or if any character is repeated.
+  pub fn new(chars: &str) -> Result<Self, &'static str> {
+    alphabet::mapping(chars).map(Alphabet)
+  }
+
+  /// The underlying `BaseCustom` character mapping.
+  pub fn mapping(&self) -> &BaseCustom<char> {
+    &self.0
+  }
+
+  /// Every character of the mapping as a string, ordered from zero.
+  pub fn to_s(&self) -> String {
+    chars_of(&self.0)
+  }
+}
+
+impl From<BaseCustom<char>> for Alphabet {
+  fn from(mapping: BaseCustom<char>) -> Alphabet {
+    Alphabet(mapping)
+  }
+}
+
+impl From<Alphabet> for BaseCustom<char> {
+  fn from(alphabet: Alphabet) -> BaseCustom<char> {
+    alphabet.0
+  }
+}
+
+fn chars_of(mapping: &BaseCustom<char>) -> String {
+  (0..mapping.base as usize).filter_map(|i| mapping.nth(i)).collect()
+}
+
+impl Serialize for Alphabet {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where S: Serializer {
+    serializer.serialize_str(&self.to_s())
+  }
+}
+
+impl<'de> Deserialize<'de> for Alphabet {
+  fn deserialize<D>(deserializer: D) -> Result<Alphabet, D::Error>
+  where D: Deserializer<'de> {
+    struct AlphabetVisitor;
+
+    impl<'de> Visitor<'de> for AlphabetVisitor {
+      type Value = Alphabet;
+
+      fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string of 2 to 255 unique characters")
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<Alphabet, E>
+      where E: de::Error {
+        Alphabet::new(value).map_err(E::custom)
+      }
+    }
+
+    deserializer.deserialize_str(AlphabetVisitor)
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DigitsRepr {
+  alphabet: Alphabet,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  value: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  places: Option<Vec<u64>>,
+}
+
+impl DigitsRepr {
+  fn into_digits(self) -> Result<Digits, &'static str> {
+    let mapping = self.alphabet.0;
+    match (self.value, self.places) {
+      (Some(value), None) => alphabet::value(&mapping, &value),
+      (None, Some(ref places)) if places.is_empty() => Err("A value needs at least one place!"),
+      (None, Some(places)) => Digits::new_zero(mapping).new_mapped(&places),
+      _ => Err("Expected exactly one of `value` or `places`!"),
+    }
+  }
+}
+
+impl Serialize for Digits {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where S: Serializer {
+    DigitsRepr {
+      alphabet: Alphabet((*self.mapping).clone()),
+      value: Some(self.to_s()),
+      places: None,
+    }.serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for Digits {
+  fn deserialize<D>(deserializer: D) -> Result<Digits, D::Error>
+  where D: Deserializer<'de> {
+    DigitsRepr::deserialize(deserializer)?.into_digits().map_err(de::Error::custom)
+  }
+}
+
+/// Writes `Digits` with the alphabet and a vector of character positions rather than
+/// the value string.  Use it with `#[serde(with = "digits::serialization::compact")]`.
+pub mod compact {
+  use serde::{Serialize, Serializer, Deserialize, Deserializer};
+  use super::{Alphabet, DigitsRepr};
+  use Digits;
+
+  /// Serializes `Digits` in the compact positional form.
+  pub fn serialize<S>(digits: &Digits, serializer: S) -> Result<S::Ok, S::Error>
+  where S: Serializer {
+    DigitsRepr {
+      alphabet: Alphabet((*digits.mapping).clone()),
+      value: None,
+      places: Some(digits.as_mapping_vec()),
+    }.serialize(serializer)
+  }
+
+  /// Deserializes `Digits` from either the compact or the value string form.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Digits, D::Error>
+  where D: Deserializer<'de> {
+    Digits::deserialize(deserializer)
+  }
+}
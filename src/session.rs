@@ -0,0 +1,311 @@
+//! Resumable enumeration that records its progress in a checkpoint file.
+//!
+//! A checkpoint is a small text file with one `key value` pair per line.  Strings are
+//! written in Rust's quoted and escaped form so any alphabet survives the round trip.
+//!
+//! ```text
+//! digits-session 1
+//! kind "range"
+//! alphabet "0123456789"
+//! next "0042"
+//! end "0999"
+//! max-adjacent 1
+//! emitted 38
+//! every 1000
+//! ```
+//!
+//! `next` is the first candidate that has not been handed out yet and is left out once
+//! the enumeration is finished.  A mask session records `mask` and, as `next`, the
+//! index into each position's characters.
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use alphabet;
+use mask::{Mask, MaskIter};
+use range::DigitsRange;
+use Digits;
+
+const HEADER: &str = "digits-session 1";
+
+#[derive(Clone, Debug)]
+enum Source {
+  Range(DigitsRange),
+  Mask(Mask, MaskIter),
+}
+
+/// Wraps a `DigitsRange` or a `Mask` enumeration so it can be stopped and later
+/// resumed from a checkpoint file without repeating or skipping candidates.
+///
+/// With a checkpoint file set, progress is saved automatically every `every`
+/// candidates, just before the next one is handed out.  A candidate only counts as
+/// done once the following one has been asked for, so after a crash at most the
+/// candidates since the last save are repeated.  `run` also saves when it stops.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::range::DigitsRange;
+/// use digits::session::Session;
+///
+/// let path = std::env::temp_dir().join("digits-session-doc.txt");
+/// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+/// let start = Digits::new(base10.clone(), "00".to_string());
+/// let end = Digits::new(base10, "99".to_string());
+///
+/// let mut session = Session::range(DigitsRange::new(start, end).unwrap())
+///   .checkpoint(&path, 10);
+/// session.run(|candidate| candidate != "41").unwrap();
+///
+/// let mut resumed = Session::restore(&path).unwrap();
+/// assert_eq!(resumed.emitted(), 42);
+/// assert_eq!(resumed.next_candidate().unwrap().unwrap(), "42");
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Session {
+  source: Source,
+  max_adjacent: Option<usize>,
+  emitted: u64,
+  checkpoint: Option<PathBuf>,
+  every: u64,
+  unsaved: u64,
+}
+
+impl Session {
+  /// Creates a session enumerating a range.
+  pub fn range(range: DigitsRange) -> Session {
+    Session::from_source(Source::Range(range))
+  }
+
+  /// Creates a session enumerating every candidate of a mask.
+  pub fn mask(mask: Mask) -> Session {
+    let iter = mask.iter();
+    Session::from_source(Source::Mask(mask, iter))
+  }
+
+  fn from_source(source: Source) -> Session {
+    Session { source, max_adjacent: None, emitted: 0, checkpoint: None, every: 0, unsaved: 0 }
+  }
+
+  /// Skips candidates with more than the given number of adjacent repeats of any
+  /// one character, as with `Digits::is_valid_adjacent`.
+  pub fn max_adjacent(mut self, adjacent: usize) -> Self {
+    self.max_adjacent = Some(adjacent);
+    self
+  }
+
+  /// Saves progress to the given file every `every` candidates.  With `every` set to
+  /// zero the file is only written by `save` and when `run` stops.
+  pub fn checkpoint<P: AsRef<Path>>(mut self, path: P, every: u64) -> Self {
+    self.checkpoint = Some(path.as_ref().to_path_buf());
+    self.every = every;
+    self
+  }
+
+  /// How many candidates have been handed out over the life of the session,
+  /// including before it was restored.
+  pub fn emitted(&self) -> u64 {
+    self.emitted
+  }
+
+  /// Whether every candidate has been handed out.
+  pub fn is_done(&self) -> bool {
+    match self.source {
+      Source::Range(ref range) => range.peek().is_none(),
+      Source::Mask(_, ref iter) => iter.position().is_none(),
+    }
+  }
+
+  /// Hands out the next candidate, saving the checkpoint first when one is due.
+  pub fn next_candidate(&mut self) -> io::Result<Option<String>> {
+    loop {
+      if self.every > 0 && self.unsaved >= self.every {
+        self.save()?;
+      }
+      let candidate = match self.source {
+        Source::Range(ref mut range) => range.next().map(|d| d.to_s()),
+        Source::Mask(_, ref mut iter) => iter.next(),
+      };
+      let candidate = match candidate {
+        Some(candidate) => candidate,
+        None => {
+          if self.every > 0 && self.unsaved > 0 { self.save()?; }
+          return Ok(None);
+        },
+      };
+      if let Some(adjacent) = self.max_adjacent {
+        if !within_adjacent(&candidate, adjacent) { continue; }
+      }
+      self.emitted += 1;
+      self.unsaved += 1;
+      return Ok(Some(candidate));
+    }
+  }
+
+  /// Calls `f` with each candidate until it returns `false` or the enumeration ends,
+  /// then saves the checkpoint.  Returns whether the enumeration finished.
+  pub fn run<F: FnMut(&str) -> bool>(&mut self, mut f: F) -> io::Result<bool> {
+    while let Some(candidate) = self.next_candidate()? {
+      if !f(&candidate) { break; }
+    }
+    self.save()?;
+    Ok(self.is_done())
+  }
+
+  /// Writes the checkpoint file, if one is set.
+  pub fn save(&mut self) -> io::Result<()> {
+    if let Some(path) = self.checkpoint.clone() {
+      self.save_to(&path)?;
+    }
+    self.unsaved = 0;
+    Ok(())
+  }
+
+  /// Writes the current progress to the given file.  The file is replaced atomically
+  /// by writing a temporary file beside it and renaming it into place.
+  pub fn save_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    let path = path.as_ref();
+    let mut text = format!("{}\n", HEADER);
+    match self.source {
+      Source::Range(ref range) => {
+        let end = range.end();
+        text += "kind \"range\"\n";
+        text += &format!("alphabet {:?}\n", alphabet(end));
+        if let Some(next) = range.peek() {
+          text += &format!("next {:?}\n", next.to_s());
+        }
+        text += &format!("end {:?}\n", end.to_s());
+      },
+      Source::Mask(ref mask, ref iter) => {
+        text += "kind \"mask\"\n";
+        text += &format!("mask {:?}\n", mask.pattern());
+        if let Some(position) = iter.position() {
+          let indices: Vec<String> = position.iter().map(|i| i.to_string()).collect();
+          text += &format!("next {}\n", indices.join(" "));
+        }
+      },
+    }
+    if let Some(adjacent) = self.max_adjacent {
+      text += &format!("max-adjacent {}\n", adjacent);
+    }
+    text += &format!("emitted {}\nevery {}\n", self.emitted, self.every);
+
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    {
+      let mut file = fs::File::create(&temp)?;
+      file.write_all(text.as_bytes())?;
+      file.sync_all()?;
+    }
+    fs::rename(&temp, path)
+  }
+
+  /// Loads a session from a checkpoint file and keeps saving to that same file.
+  pub fn restore<P: AsRef<Path>>(path: P) -> io::Result<Session> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)?;
+    let mut lines = text.lines();
+    if lines.next() != Some(HEADER) {
+      return Err(invalid("Not a digits session checkpoint!"));
+    }
+    let mut fields: Vec<(&str, &str)> = vec![];
+    for line in lines.filter(|l| !l.is_empty()) {
+      let mut parts = line.splitn(2, ' ');
+      let key = parts.next().unwrap();
+      fields.push((key, parts.next().unwrap_or("")));
+    }
+    let field = |key: &str| fields.iter().find(|f| f.0 == key).map(|f| f.1);
+    let string = |key: &str| -> io::Result<Option<String>> {
+      field(key).map(unquote).transpose()
+    };
+    let number = |key: &str| -> io::Result<Option<u64>> {
+      field(key).map(|v| v.parse::<u64>().map_err(|_| invalid("Malformed number in checkpoint!"))).transpose()
+    };
+
+    let source = match string("kind")?.as_deref() {
+      Some("range") => {
+        let chars = string("alphabet")?.ok_or_else(|| invalid("Checkpoint has no alphabet!"))?;
+        let mapping = alphabet::mapping(&chars).map_err(invalid)?;
+        let end = string("end")?.ok_or_else(|| invalid("Checkpoint has no end!"))?;
+        let end = alphabet::value(&mapping, &end).map_err(invalid)?;
+        let start = match string("next")? {
+          Some(next) => alphabet::value(&mapping, &next).map_err(invalid)?,
+          None => { let mut past = end.clone(); past.succ_in_place(); past },
+        };
+        Source::Range(DigitsRange::new(start, end).map_err(invalid)?)
+      },
+      Some("mask") => {
+        let pattern = string("mask")?.ok_or_else(|| invalid("Checkpoint has no mask!"))?;
+        let mask = Mask::new(&pattern).map_err(invalid)?;
+        let iter = match field("next") {
+          Some(next) => {
+            let position = next.split(' ')
+              .map(|i| i.parse::<usize>().map_err(|_| invalid("Malformed mask position in checkpoint!")))
+              .collect::<io::Result<Vec<usize>>>()?;
+            mask.iter_from(&position).map_err(invalid)?
+          },
+          None => mask.iter_finished(),
+        };
+        Source::Mask(mask, iter)
+      },
+      _ => return Err(invalid("Unknown checkpoint kind!")),
+    };
+
+    let mut session = Session::from_source(source);
+    session.max_adjacent = number("max-adjacent")?.map(|n| n as usize);
+    session.emitted = number("emitted")?.unwrap_or(0);
+    session.every = number("every")?.unwrap_or(0);
+    session.checkpoint = Some(path.to_path_buf());
+    Ok(session)
+  }
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn within_adjacent(candidate: &str, adjacent: usize) -> bool {
+  let mut last = None;
+  let mut repeats = 0;
+  for c in candidate.chars() {
+    if Some(c) == last { repeats += 1; } else { repeats = 0; }
+    if repeats > adjacent { return false; }
+    last = Some(c);
+  }
+  true
+}
+
+fn alphabet(d: &Digits) -> String {
+  (0..d.base()).filter_map(|i| d.mapping.char(i)).collect()
+}
+
+// Reverses the `{:?}` quoting of a string.
+fn unquote(text: &str) -> io::Result<String> {
+  let malformed = || invalid("Malformed string in checkpoint!");
+  if text.len() < 2 || !text.starts_with('"') || !text.ends_with('"') {
+    return Err(malformed());
+  }
+  let mut result = String::with_capacity(text.len());
+  let mut chars = text[1..text.len() - 1].chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' { result.push(c); continue; }
+    let escaped = match chars.next().ok_or_else(malformed)? {
+      'n' => '\n',
+      'r' => '\r',
+      't' => '\t',
+      '0' => '\0',
+      '\\' => '\\',
+      '"' => '"',
+      '\'' => '\'',
+      'u' => {
+        if chars.next() != Some('{') { return Err(malformed()); }
+        let hex: String = chars.by_ref().take_while(|&c| c != '}').collect();
+        u32::from_str_radix(&hex, 16).ok().and_then(std::char::from_u32).ok_or_else(malformed)?
+      },
+      _ => return Err(malformed()),
+    };
+    result.push(escaped);
+  }
+  Ok(result)
+}
@@ -0,0 +1,224 @@
+//! A keyed, format preserving shuffle of every value of a fixed length.
+//!
+//! `Shuffle` maps each index in `[0, base^length)` to a unique value of the same
+//! alphabet and length, so walking the indices in order visits the whole keyspace in a
+//! scrambled yet exhaustive order that can be resumed from any index.
+//!
+//! It is a Feistel network over the places themselves: the value is split into two
+//! halves and each of ten rounds adds a keyed hash of one half onto the other, modulo
+//! the base to the power of that half's length.  Single place keyspaces are too short
+//! to split, so they are shuffled as two places with cycle-walking: the permutation is
+//! applied again until the leading place is zero.
+//!
+//! _The round function is a fast 64 bit mixer, not a cryptographic one.  The shuffle
+//! spreads candidates out but must not be relied on to hide them._
+use std::sync::Arc;
+use internal::places;
+use {BaseCustom, Digits};
+
+const ROUNDS: u64 = 10;
+
+/// A keyed bijection between indices and values of a fixed length.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::shuffle::Shuffle;
+///
+/// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+/// let shuffle = Shuffle::new(base10, 3, 0xC0FFEE).unwrap();
+///
+/// let mut seen: Vec<String> = shuffle.iter().map(|d| d.to_s()).collect();
+/// assert_ne!(seen[..3], ["000", "001", "002"]);
+///
+/// seen.sort();
+/// assert_eq!(seen.len(), 1000);
+/// seen.dedup();
+/// assert_eq!(seen.len(), 1000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Shuffle {
+  mapping: Arc<BaseCustom<char>>,
+  length: usize,
+  key: u64,
+}
+
+impl Shuffle {
+  /// Creates a shuffle of all values `length` places long in `mapping`, keyed by `key`.
+  ///
+  /// Returns an `Err(&'static str)` Result if `length` is zero.
+  pub fn new(mapping: BaseCustom<char>, length: usize, key: u64) -> Result<Shuffle, &'static str> {
+    if length == 0 { return Err("A shuffle needs at least one place!"); }
+    Ok(Shuffle { mapping: Arc::new(mapping), length, key })
+  }
+
+  /// The number of values being shuffled, `base^length`, in the shuffle's mapping.
+  pub fn keyspace(&self) -> Digits {
+    let mut places = vec![0; self.length + 1];
+    places[0] = 1;
+    Digits::from_places(self.mapping.clone(), &places)
+  }
+
+  /// The value at the given index.  The result is zero padded to the shuffle's length.
+  ///
+  /// Returns an `Err(&'static str)` Result if the index uses another mapping or is not
+  /// below the keyspace.
+  pub fn permute(&self, index: &Digits) -> Result<Digits, &'static str> {
+    let mut value = self.places_of(index)?;
+    self.walk(&mut value, |s, v| s.encrypt(v));
+    Ok(Digits::from_places(self.mapping.clone(), &value[value.len() - self.length..]))
+  }
+
+  /// The index of the given value, undoing `permute`.  The result is zero padded to
+  /// the shuffle's length.
+  ///
+  /// Returns an `Err(&'static str)` Result if the value uses another mapping or is
+  /// longer than the shuffle's length.
+  pub fn invert(&self, value: &Digits) -> Result<Digits, &'static str> {
+    let mut index = self.places_of(value)?;
+    self.walk(&mut index, |s, v| s.decrypt(v));
+    Ok(Digits::from_places(self.mapping.clone(), &index[index.len() - self.length..]))
+  }
+
+  /// Iterates over the shuffled values for every index in order.
+  pub fn iter(&self) -> ShuffleIter {
+    ShuffleIter { shuffle: self.clone(), next: Some(vec![0; self.length]) }
+  }
+
+  /// Iterates over the shuffled values from the given index onwards.
+  ///
+  /// Returns an `Err(&'static str)` Result if the index does not fit the keyspace.
+  pub fn iter_from(&self, index: &Digits) -> Result<ShuffleIter, &'static str> {
+    let places = self.places_of(index)?;
+    Ok(ShuffleIter { shuffle: self.clone(), next: Some(places[places.len() - self.length..].to_vec()) })
+  }
+
+  // The places of `d` padded to the working width, which is one place wider than the
+  // length when cycle-walking.
+  fn places_of(&self, d: &Digits) -> Result<Vec<u64>, &'static str> {
+    if d.mapping != self.mapping {
+      return Err("Incompatible numeric base mappings!");
+    }
+    let all = d.as_mapping_vec();
+    let significant = places::trimmed(&all);
+    if significant.len() > self.length { return Err("Value is outside of the keyspace!"); }
+    let width = std::cmp::max(self.length, 2);
+    let mut result = vec![0; width - significant.len()];
+    result.extend_from_slice(significant);
+    Ok(result)
+  }
+
+  // Applies `step` until the value lands back inside the keyspace.
+  fn walk<F: Fn(&Shuffle, &mut [u64])>(&self, value: &mut [u64], step: F) {
+    let extra = value.len() - self.length;
+    loop {
+      step(self, value);
+      if value[..extra].iter().all(|&x| x == 0) { return; }
+    }
+  }
+
+  fn encrypt(&self, value: &mut [u64]) {
+    let split = value.len() / 2;
+    let (mut a, mut b) = (value[..split].to_vec(), value[split..].to_vec());
+    for round in 0..ROUNDS {
+      let f = self.round(round, &b, a.len());
+      add_mod(&mut a, &f, self.mapping.base);
+      std::mem::swap(&mut a, &mut b);
+    }
+    value[..split].copy_from_slice(&a);
+    value[split..].copy_from_slice(&b);
+  }
+
+  fn decrypt(&self, value: &mut [u64]) {
+    let split = value.len() / 2;
+    let (mut a, mut b) = (value[..split].to_vec(), value[split..].to_vec());
+    for round in (0..ROUNDS).rev() {
+      std::mem::swap(&mut a, &mut b);
+      let f = self.round(round, &b, a.len());
+      sub_mod(&mut a, &f, self.mapping.base);
+    }
+    value[..split].copy_from_slice(&a);
+    value[split..].copy_from_slice(&b);
+  }
+
+  // A keyed hash of `half` spread over `width` places.
+  fn round(&self, round: u64, half: &[u64], width: usize) -> Vec<u64> {
+    let mut state = mix(self.key ^ mix(round ^ ((half.len() as u64) << 32)));
+    for &place in half {
+      state = mix(state ^ place);
+    }
+    (0..width).map(|_| {
+      state = mix(state);
+      state % self.mapping.base
+    }).collect()
+  }
+}
+
+// The SplitMix64 finaliser.
+fn mix(mut z: u64) -> u64 {
+  z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+  z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+  z ^ (z >> 31)
+}
+
+// `a = (a + b) mod base^a.len()` for equal length place vectors.
+fn add_mod(a: &mut [u64], b: &[u64], base: u64) {
+  let mut carry = 0;
+  for (x, &y) in a.iter_mut().zip(b).rev() {
+    let sum = *x + y + carry;
+    *x = sum % base;
+    carry = sum / base;
+  }
+}
+
+// `a = (a - b) mod base^a.len()` for equal length place vectors.
+fn sub_mod(a: &mut [u64], b: &[u64], base: u64) {
+  let mut borrow = 0;
+  for (x, &y) in a.iter_mut().zip(b).rev() {
+    let subtrahend = y + borrow;
+    if *x >= subtrahend {
+      *x -= subtrahend;
+      borrow = 0;
+    } else {
+      *x = *x + base - subtrahend;
+      borrow = 1;
+    }
+  }
+}
+
+/// An iterator over shuffled values in index order, created by `Shuffle::iter`.
+#[derive(Clone, Debug)]
+pub struct ShuffleIter {
+  shuffle: Shuffle,
+  next: Option<Vec<u64>>,
+}
+
+impl ShuffleIter {
+  /// The index whose value `next` will yield, or `None` once every index is done.
+  /// Pass it to `Shuffle::iter_from` to resume.
+  pub fn position(&self) -> Option<Digits> {
+    self.next.as_ref().map(|index| Digits::from_places(self.shuffle.mapping.clone(), index))
+  }
+}
+
+impl Iterator for ShuffleIter {
+  type Item = Digits;
+
+  fn next(&mut self) -> Option<Digits> {
+    let index = self.next.take()?;
+    let value = self.shuffle.permute(&Digits::from_places(self.shuffle.mapping.clone(), &index)).unwrap();
+    let mut following = index;
+    let top = self.shuffle.mapping.base - 1;
+    for i in (0..following.len()).rev() {
+      if following[i] < top {
+        following[i] += 1;
+        self.next = Some(following);
+        break;
+      }
+      following[i] = 0;
+    }
+    Some(value)
+  }
+}
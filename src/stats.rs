@@ -0,0 +1,139 @@
+//! Character statistics for scoring and filtering candidates.
+//!
+//! Every count covers the whole value including its zero padding, as the value would
+//! be written out by `to_s`.
+use Digits;
+
+/// How many characters of a value fall in each character class.  The classes match
+/// the `?l`, `?u`, `?d` and `?s` mask placeholders; anything else is `other`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CharClasses {
+  /// Lowercase ASCII letters.
+  pub lower: usize,
+  /// Uppercase ASCII letters.
+  pub upper: usize,
+  /// ASCII digits.
+  pub digit: usize,
+  /// The space and ASCII punctuation.
+  pub special: usize,
+  /// Every other character.
+  pub other: usize,
+}
+
+impl CharClasses {
+  /// How many of the classes have at least one character.  Password policies often
+  /// ask for three or four.
+  pub fn variety(&self) -> usize {
+    [self.lower, self.upper, self.digit, self.special, self.other].iter().filter(|&&n| n > 0).count()
+  }
+}
+
+impl Digits {
+  /// Counts each character class in this value.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let mixed = BaseCustom::<char>::new("aB3!".chars().collect());
+  /// let num = Digits::new(mixed, "BaB!3a".to_string());
+  /// let classes = num.char_classes();
+  ///
+  /// assert_eq!((classes.lower, classes.upper, classes.digit, classes.special), (2, 2, 1, 1));
+  /// assert_eq!(classes.variety(), 4);
+  /// ```
+  pub fn char_classes(&self) -> CharClasses {
+    let mut classes = CharClasses::default();
+    for (i, &count) in self.histogram().iter().enumerate() {
+      let c = self.mapping.char(i).unwrap();
+      let class = match c {
+        'a'..='z' => &mut classes.lower,
+        'A'..='Z' => &mut classes.upper,
+        '0'..='9' => &mut classes.digit,
+        ' ' => &mut classes.special,
+        _ if c.is_ascii_punctuation() => &mut classes.special,
+        _ => &mut classes.other,
+      };
+      *class += count;
+    }
+    classes
+  }
+
+  /// How many different characters this value uses.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// assert_eq!(Digits::new(base10, "0090".to_string()).distinct(), 2);
+  /// ```
+  pub fn distinct(&self) -> usize {
+    self.histogram().iter().filter(|&&n| n > 0).count()
+  }
+
+  /// The Shannon entropy of this value's characters in bits per character.  A value
+  /// of one repeated character scores zero and one using `n` characters equally often
+  /// scores `log2(n)`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  ///
+  /// assert_eq!(Digits::new(base16.clone(), "AAAA".to_string()).entropy(), 0.0);
+  /// assert_eq!(Digits::new(base16, "ABCD".to_string()).entropy(), 2.0);
+  /// ```
+  pub fn entropy(&self) -> f64 {
+    let histogram = self.histogram();
+    let total = histogram.iter().sum::<usize>() as f64;
+    histogram.iter().filter(|&&n| n > 0).map(|&n| {
+      let p = n as f64 / total;
+      -p * p.log2()
+    }).sum::<f64>().max(0.0)
+  }
+
+  /// How often each character of the mapping occurs in this value, indexed by the
+  /// character's position in the mapping.  Characters that do not occur count zero.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  /// let num = Digits::new(base3, "caab".to_string());
+  ///
+  /// assert_eq!(num.histogram(), vec![2, 1, 1]);
+  /// ```
+  pub fn histogram(&self) -> Vec<usize> {
+    let mut counts = vec![0; self.mapping.base as usize];
+    for node in self.nodes() {
+      counts[node.digit as usize] += 1;
+    }
+    counts
+  }
+
+  /// Each run of a repeated character in this value with its length, most
+  /// significant first.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::prelude::*;
+  ///
+  /// let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  /// let num = Digits::new(base10, "557771".to_string());
+  ///
+  /// assert_eq!(num.runs(), vec![('5', 2), ('7', 3), ('1', 1)]);
+  /// ```
+  pub fn runs(&self) -> Vec<(char, usize)> {
+    self.to_rle().into_iter()
+      .map(|(index, length)| (self.mapping.char(index as usize).unwrap(), length))
+      .collect()
+  }
+}
@@ -0,0 +1,230 @@
+//! Streams enumerated candidates straight into any `io::Write`.
+//!
+//! Calling `to_s` on every value of a long enumeration allocates a new `String` each
+//! time.  `CandidateWriter` keeps one output buffer instead and, when writing a
+//! `DigitsRange` or a `Mask`, only rewrites the trailing characters that changed
+//! since the previous candidate.
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, Write};
+use internal::places;
+use mask::Mask;
+use range::DigitsRange;
+use {BaseCustom, Digits};
+
+/// Writes candidates to an `io::Write`, each as `prefix`, value, `suffix` and then
+/// `separator`.  The separator defaults to a newline, giving one candidate per line.
+///
+/// Every candidate is handed to the writer with a single `write_all`, so wrap
+/// unbuffered outputs such as files or stdout in an `io::BufWriter`.
+///
+/// # Example
+///
+/// ```
+/// use digits::prelude::*;
+/// use digits::range::DigitsRange;
+/// use digits::writer::CandidateWriter;
+///
+/// let base2 = BaseCustom::<char>::new("01".chars().collect());
+/// let start = Digits::new(base2.clone(), "00".to_string());
+/// let end = Digits::new(base2, "11".to_string());
+///
+/// let mut writer = CandidateWriter::new(vec![]).prefix("pin-").separator(",");
+/// let written = writer.write_range(DigitsRange::new(start, end).unwrap()).unwrap();
+///
+/// assert_eq!(written, 4);
+/// assert_eq!(writer.into_inner(), b"pin-00,pin-01,pin-10,pin-11,");
+/// ```
+pub struct CandidateWriter<W: Write> {
+  out: W,
+  prefix: Vec<u8>,
+  suffix: Vec<u8>,
+  separator: Vec<u8>,
+  line: Vec<u8>,
+}
+
+impl<W: Write> CandidateWriter<W> {
+  /// Creates a writer with no prefix or suffix and a newline separator.
+  pub fn new(out: W) -> Self {
+    CandidateWriter {
+      out,
+      prefix: vec![],
+      suffix: vec![],
+      separator: b"\n".to_vec(),
+      line: vec![],
+    }
+  }
+
+  /// Text written before every candidate.
+  pub fn prefix(mut self, prefix: &str) -> Self {
+    self.prefix = prefix.as_bytes().to_vec();
+    self
+  }
+
+  /// Text written after every candidate, before the separator.
+  pub fn suffix(mut self, suffix: &str) -> Self {
+    self.suffix = suffix.as_bytes().to_vec();
+    self
+  }
+
+  /// Text written after every candidate and its suffix.
+  pub fn separator(mut self, separator: &str) -> Self {
+    self.separator = separator.as_bytes().to_vec();
+    self
+  }
+
+  /// Writes a single candidate.
+  pub fn write_digits(&mut self, digits: &Digits) -> io::Result<()> {
+    let glyphs = glyphs(&digits.mapping);
+    self.line.clear();
+    self.line.extend_from_slice(&self.prefix);
+    for place in digits.as_mapping_vec() {
+      self.line.extend_from_slice(&glyphs[place as usize]);
+    }
+    self.line.extend_from_slice(&self.suffix);
+    self.line.extend_from_slice(&self.separator);
+    self.out.write_all(&self.line)
+  }
+
+  /// Writes every candidate yielded by an iterator, returning how many were written.
+  pub fn write_iter<I>(&mut self, candidates: I) -> io::Result<u64>
+  where I: IntoIterator<Item = Digits> {
+    let mut count = 0;
+    for digits in candidates {
+      self.write_digits(&digits)?;
+      count += 1;
+    }
+    Ok(count)
+  }
+
+  /// Writes every remaining value of the range, returning how many were written.
+  ///
+  /// Between consecutive values only the positions touched by the carry are
+  /// re-encoded into the output buffer.
+  pub fn write_range(&mut self, range: DigitsRange) -> io::Result<u64> {
+    let start = match range.peek() {
+      Some(start) => start,
+      None => return Ok(0),
+    };
+    let glyphs = glyphs(&start.mapping);
+    let top = start.mapping.base - 1;
+    let end = range.end().as_mapping_vec();
+    let mut value = start.as_mapping_vec();
+
+    // `offsets[i]` is where the character for `value[i]` begins within `line`.
+    let mut offsets: Vec<usize> = Vec::with_capacity(value.len() + 1);
+    let mut changed = 0;
+    let mut count = 0;
+    self.line.clear();
+    self.line.extend_from_slice(&self.prefix);
+    loop {
+      self.line.truncate(offsets.get(changed).cloned().unwrap_or(self.prefix.len()));
+      offsets.truncate(changed);
+      for &place in &value[changed..] {
+        offsets.push(self.line.len());
+        self.line.extend_from_slice(&glyphs[place as usize]);
+      }
+      self.line.extend_from_slice(&self.suffix);
+      self.line.extend_from_slice(&self.separator);
+      self.out.write_all(&self.line)?;
+      count += 1;
+
+      if places::cmp(&value, &end) != Ordering::Less { return Ok(count); }
+
+      changed = value.len();
+      loop {
+        if changed == 0 {
+          value.insert(0, 1);
+          offsets.clear();
+          break;
+        }
+        changed -= 1;
+        if value[changed] == top {
+          value[changed] = 0;
+        } else {
+          value[changed] += 1;
+          break;
+        }
+      }
+    }
+  }
+
+  /// Writes every candidate of the mask, returning how many were written.
+  ///
+  /// As with `write_range` only the positions that changed are re-encoded.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use digits::mask::Mask;
+  /// use digits::writer::CandidateWriter;
+  ///
+  /// let mut writer = CandidateWriter::new(vec![]).separator(" ");
+  /// writer.write_mask(&Mask::new("x?d").unwrap()).unwrap();
+  ///
+  /// assert_eq!(writer.into_inner(), b"x0 x1 x2 x3 x4 x5 x6 x7 x8 x9 ");
+  /// ```
+  pub fn write_mask(&mut self, mask: &Mask) -> io::Result<u64> {
+    let glyphs: Vec<Vec<Vec<u8>>> = mask.positions().iter().map(|set| {
+      set.iter().map(|&c| encode(c)).collect()
+    }).collect();
+    let mut indices = vec![0usize; glyphs.len()];
+
+    let mut offsets: Vec<usize> = Vec::with_capacity(indices.len());
+    let mut changed = 0;
+    let mut count = 0;
+    self.line.clear();
+    self.line.extend_from_slice(&self.prefix);
+    loop {
+      self.line.truncate(offsets.get(changed).cloned().unwrap_or(self.prefix.len()));
+      offsets.truncate(changed);
+      for (&i, set) in indices[changed..].iter().zip(&glyphs[changed..]) {
+        offsets.push(self.line.len());
+        self.line.extend_from_slice(&set[i]);
+      }
+      self.line.extend_from_slice(&self.suffix);
+      self.line.extend_from_slice(&self.separator);
+      self.out.write_all(&self.line)?;
+      count += 1;
+
+      changed = indices.len();
+      loop {
+        if changed == 0 { return Ok(count); }
+        changed -= 1;
+        indices[changed] += 1;
+        if indices[changed] < glyphs[changed].len() { break; }
+        indices[changed] = 0;
+      }
+    }
+  }
+
+  /// Flushes the underlying writer.
+  pub fn flush(&mut self) -> io::Result<()> {
+    self.out.flush()
+  }
+
+  /// Returns the underlying writer.
+  pub fn into_inner(self) -> W {
+    self.out
+  }
+}
+
+impl<W: Write> fmt::Debug for CandidateWriter<W> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("CandidateWriter")
+      .field("prefix", &String::from_utf8_lossy(&self.prefix))
+      .field("suffix", &String::from_utf8_lossy(&self.suffix))
+      .field("separator", &String::from_utf8_lossy(&self.separator))
+      .finish()
+  }
+}
+
+fn encode(c: char) -> Vec<u8> {
+  let mut buf = [0; 4];
+  c.encode_utf8(&mut buf).as_bytes().to_vec()
+}
+
+// The UTF-8 bytes of every character in the mapping, indexed by position.
+fn glyphs(mapping: &BaseCustom<char>) -> Vec<Vec<u8>> {
+  (0..mapping.base as usize).map(|i| encode(*mapping.nth(i).unwrap())).collect()
+}
@@ -0,0 +1,22 @@
+extern crate digits;
+use digits::alphabet;
+
+#[test]
+fn it_validates_alphabets() {
+  assert_eq!(alphabet::mapping("0123456789").unwrap().base, 10);
+  assert_eq!(alphabet::mapping("é\u{301}").unwrap().base, 2);
+  assert_eq!(alphabet::mapping("a").err(), Some("Too few characters for an alphabet!"));
+  let too_many: String = (0..256u32).filter_map(|i| std::char::from_u32(0x100 + i)).collect();
+  assert_eq!(alphabet::mapping(&too_many).err(), Some("Too many characters for an alphabet!"));
+  assert_eq!(alphabet::mapping("abca").err(), Some("Alphabet characters must be unique!"));
+}
+
+#[test]
+fn it_reads_values_in_an_alphabet() {
+  let letters = alphabet::mapping("abc").unwrap();
+  let num = alphabet::value(&letters, "aacb").unwrap();
+  assert_eq!(num.to_s(), "aacb");
+  assert_eq!(num.as_mapping_vec(), vec![0, 0, 2, 1]);
+  assert_eq!(alphabet::value(&letters, "abz").err(), Some("Value has characters outside of its alphabet!"));
+  assert_eq!(alphabet::value(&letters, "").err(), Some("A value needs at least one character!"));
+}
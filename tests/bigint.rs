@@ -0,0 +1,55 @@
+#![cfg(feature="num-bigint")]
+extern crate digits;
+extern crate num_bigint;
+use digits::prelude::*;
+use digits::Radix;
+use num_bigint::{BigUint,BigInt};
+use std::convert::TryFrom;
+
+#[test]
+fn it_converts_to_and_from_biguint() {
+  let letters = BaseCustom::<char>::new("abcdefghijklmnopqrstuvwxyz".chars().collect());
+  let num = Digits::new(letters.clone(), "aaxyz".to_string());
+  let big = BigUint::from(&num);
+  assert_eq!(big, BigUint::from(23u32 * 676 + 24 * 26 + 25));
+  assert_eq!(Digits::from((letters.clone(), big)).to_s(), "xyz");
+  assert_eq!(Digits::from((letters, BigUint::from(0u32))).to_s(), "a");
+}
+
+#[test]
+fn it_converts_to_and_from_bigint() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base10.clone(), "123456789012345678901234567890".to_string());
+  let big = BigInt::from(&num);
+  assert_eq!(big.to_string(), "123456789012345678901234567890");
+  assert_eq!(Digits::try_from((base10.clone(), big)).unwrap().to_s(), num.to_s());
+  assert!(Digits::try_from((base10, BigInt::from(-5))).is_err());
+}
+
+fn generic_try_from<'a, T>(d: &'a Digits) -> T
+where T: TryFrom<&'a Digits>, T::Error: std::fmt::Debug {
+  T::try_from(d).unwrap()
+}
+
+#[test]
+fn digits_to_biguint_works_with_try_from() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base10, "42".to_string());
+  assert_eq!(generic_try_from::<BigUint>(&num), BigUint::from(42u32));
+}
+
+#[test]
+fn it_delegates_heavy_arithmetic() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut two = Digits::new(base10.clone(), "2".to_string());
+  let pwr = two.gen(256u64);
+  assert_eq!(
+    two.pow(pwr).to_s(),
+    "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+  );
+  let a = Digits::new(base10.clone(), "99999999999999999999".to_string());
+  assert_eq!(a.mul(a.clone()).to_s(), "9999999999999999999800000000000000000001");
+  let wide = Digits::new(base10, "340282366920938463463374607431768211455".to_string());
+  assert_eq!(wide.hex().to_s(), "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+  assert_eq!(wide.hex().decimal().to_s(), wide.to_s());
+}
@@ -0,0 +1,115 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::binary::{fingerprint,FORMAT_VERSION};
+use std::io::ErrorKind;
+
+// A small xorshift generator so the round trips are reproducible.
+struct XorShift(u64);
+
+impl XorShift {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+}
+
+fn encode(d: &Digits) -> Vec<u8> {
+  let mut buffer = vec![];
+  d.write_to(&mut buffer).unwrap();
+  buffer
+}
+
+#[test]
+fn it_writes_the_documented_layout() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base10.clone(), "0042".to_string());
+  let bytes = encode(&num);
+  assert_eq!(&bytes[0..3], b"DGT");
+  assert_eq!(bytes[3], FORMAT_VERSION);
+  assert_eq!(&bytes[4..12], &fingerprint(&base10).to_le_bytes());
+  assert_eq!(&bytes[12..], &[4, 0, 0, 4, 2]);
+}
+
+#[test]
+fn fuzz_round_trips_preserve_exact_digits() {
+  let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+  let alphabets = [
+    "01",
+    "0123456789",
+    "0123456789abcdef",
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789+/",
+  ];
+  for _ in 0..500 {
+    let chars: Vec<char> = alphabets[(rng.next() % 4) as usize].chars().collect();
+    let base = chars.len() as u64;
+    let mapping = BaseCustom::<char>::new(chars);
+    let len = 1 + (rng.next() % 40) as usize;
+    let places: Vec<u64> = (0..len).map(|_| rng.next() % base).collect();
+    let num = Digits::new_zero(mapping.clone()).new_mapped(&places).unwrap();
+    let bytes = encode(&num);
+    let loaded = Digits::read_from(mapping, &mut &bytes[..]).unwrap();
+    assert!(loaded.eq_exact(&num), "{} != {}", loaded.to_s(), num.to_s());
+  }
+}
+
+#[test]
+fn it_uses_multi_byte_varints_for_large_bases() {
+  let wide = BaseCustom::<char>::from_ordinal_range(32..127);
+  let num = Digits::new(wide.clone(), "~ ".to_string());
+  let bytes = encode(&num);
+  assert_eq!(&bytes[12..], &[2, 94, 0]);
+  let chars: Vec<char> = (0..200u32).map(|i| std::char::from_u32(0x100 + i).unwrap()).collect();
+  let big = BaseCustom::<char>::new(chars);
+  let num = Digits::new_zero(big.clone()).new_mapped(&[199, 128, 1]).unwrap();
+  let bytes = encode(&num);
+  assert_eq!(&bytes[12..], &[3, 0xc7, 0x01, 0x80, 0x01, 1]);
+  assert!(Digits::read_from(big, &mut &bytes[..]).unwrap().eq_exact(&num));
+}
+
+#[test]
+fn it_rejects_a_different_alphabet() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let letters = BaseCustom::<char>::new("ABCDEFGHIJ".chars().collect());
+  let bytes = encode(&Digits::new(base10, "123".to_string()));
+  let err = Digits::read_from(letters, &mut &bytes[..]).unwrap_err();
+  assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn it_rejects_corrupt_records() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let bytes = encode(&Digits::new(base10.clone(), "123".to_string()));
+
+  let mut bad_magic = bytes.clone();
+  bad_magic[0] = b'X';
+  assert_eq!(Digits::read_from(base10.clone(), &mut &bad_magic[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+  let mut bad_version = bytes.clone();
+  bad_version[3] = FORMAT_VERSION + 1;
+  assert_eq!(Digits::read_from(base10.clone(), &mut &bad_version[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+  let mut out_of_range = bytes.clone();
+  *out_of_range.last_mut().unwrap() = 10;
+  assert_eq!(Digits::read_from(base10.clone(), &mut &out_of_range[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+  for cut in 0..bytes.len() {
+    assert!(Digits::read_from(base10.clone(), &mut &bytes[..cut]).is_err());
+  }
+}
+
+#[test]
+fn it_reads_consecutive_records_from_one_stream() {
+  let base16 = BaseCustom::<char>::new("0123456789abcdef".chars().collect());
+  let values = ["0", "ff", "00a1", "deadbeef"];
+  let mut buffer = vec![];
+  for v in values.iter() {
+    Digits::new(base16.clone(), v.to_string()).write_to(&mut buffer).unwrap();
+  }
+  let mut reader = &buffer[..];
+  for v in values.iter() {
+    assert_eq!(Digits::read_from(base16.clone(), &mut reader).unwrap().to_s(), *v);
+  }
+  assert!(reader.is_empty());
+}
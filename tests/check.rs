@@ -0,0 +1,91 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::check::{CheckScheme, CheckedRange};
+use digits::range::DigitsRange;
+
+const SCHEMES: [CheckScheme; 3] = [CheckScheme::LuhnModN, CheckScheme::Damm, CheckScheme::Verhoeff];
+
+#[test]
+fn known_check_digits() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let check = |payload: &str, scheme| Digits::new(base10.clone(), payload.to_string()).check_char(scheme).unwrap();
+  assert_eq!(check("7992739871", CheckScheme::LuhnModN), '3');
+  assert_eq!(check("4992739871", CheckScheme::LuhnModN), '6');
+  assert_eq!(check("572", CheckScheme::Damm), '4');
+  assert_eq!(check("12345", CheckScheme::Verhoeff), '1');
+  assert_eq!(check("142857", CheckScheme::Verhoeff), '0');
+}
+
+#[test]
+fn luhn_mod_n_works_in_any_alphabet() {
+  let base36 = BaseCustom::<char>::new("0123456789abcdefghijklmnopqrstuvwxyz".chars().collect());
+  let base6 = BaseCustom::<char>::new("abcdef".chars().collect());
+  let id = Digits::new(base36.clone(), "k3yz".to_string()).with_check(CheckScheme::LuhnModN).unwrap();
+  assert_eq!(id.length(), 5);
+  assert_eq!(id.has_valid_check(CheckScheme::LuhnModN), Ok(true));
+
+  // "abcdef" from the Luhn mod N article, alphabet "abcdef" gives check "e".
+  let article = Digits::new(base6, "abcdef".to_string());
+  assert_eq!(article.check_char(CheckScheme::LuhnModN), Ok('e'));
+}
+
+#[test]
+fn single_substitutions_are_caught() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  for &scheme in &SCHEMES {
+    let id = Digits::new(base10.clone(), "31415926".to_string()).with_check(scheme).unwrap();
+    let text = id.to_s();
+    for i in 0..text.len() {
+      for c in "0123456789".chars().filter(|&c| Some(c) != text.chars().nth(i)) {
+        let mut typo: Vec<char> = text.chars().collect();
+        typo[i] = c;
+        let typo = Digits::new(base10.clone(), typo.into_iter().collect::<String>());
+        assert_eq!(typo.has_valid_check(scheme), Ok(false), "{:?} {}", scheme, typo.to_s());
+      }
+    }
+  }
+}
+
+#[test]
+fn damm_and_verhoeff_catch_every_adjacent_swap() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  for &scheme in &SCHEMES[1..] {
+    for a in 0..10u64 {
+      for b in 0..10u64 {
+        if a == b { continue; }
+        let payload = Digits::new(base10.clone(), format!("7{}{}5", a, b));
+        let swapped = Digits::new(base10.clone(), format!("7{}{}5", b, a));
+        assert_ne!(payload.check_char(scheme), swapped.check_char(scheme), "{:?}", scheme);
+      }
+    }
+  }
+}
+
+#[test]
+fn checked_ranges_yield_only_valid_values() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let start = Digits::new(base10.clone(), "000".to_string());
+  let end = Digits::new(base10.clone(), "999".to_string());
+  for &scheme in &SCHEMES {
+    let range = DigitsRange::new(start.clone(), end.clone()).unwrap();
+    let values: Vec<Digits> = CheckedRange::new(range, scheme).unwrap().collect();
+    assert_eq!(values.len(), 1000);
+    assert!(values.iter().all(|v| v.length() == 4 && v.has_valid_check(scheme) == Ok(true)));
+  }
+  let all = DigitsRange::new(Digits::new(base10.clone(), "0000".to_string()), Digits::new(base10, "9999".to_string())).unwrap();
+  let brute = all.filter(|v| v.has_valid_check(CheckScheme::Damm) == Ok(true)).count();
+  assert_eq!(brute, 1000);
+}
+
+#[test]
+fn rejects_unsupported_alphabets() {
+  let base16 = BaseCustom::<char>::new("0123456789abcdef".chars().collect());
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let hex = Digits::new(base16, "beef".to_string());
+  assert!(hex.check_char(CheckScheme::Damm).is_err());
+  assert!(hex.with_check(CheckScheme::Verhoeff).is_err());
+  assert!(hex.has_valid_check(CheckScheme::LuhnModN).is_ok());
+  assert!(Digits::new(base10, "5".to_string()).has_valid_check(CheckScheme::Damm).is_err());
+  let range = DigitsRange::new(hex.clone(), hex).unwrap();
+  assert!(CheckedRange::new(range, CheckScheme::Damm).is_err());
+}
@@ -0,0 +1,86 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn digits(args: &[&str]) -> Output {
+  Command::new(env!("CARGO_BIN_EXE_digits")).args(args).output().unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+  let output = digits(args);
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+  String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn gen_writes_a_range() {
+  assert_eq!(stdout(&["gen", "--charset", "ab", "--length", "2"]), "aa\nab\nba\nbb\n");
+  assert_eq!(stdout(&["gen", "--charset", "abc", "--start", "bc", "--end", "ca"]), "bc\nca\n");
+  assert_eq!(stdout(&["gen", "--end", "3", "--prefix", "n", "--separator", "\\t"]), "n0\tn1\tn2\tn3\t");
+}
+
+#[test]
+fn count_sizes_ranges_and_masks() {
+  assert_eq!(stdout(&["count", "--charset", "abc", "--start", "b", "--end", "ccc"]), "26\n");
+  assert_eq!(stdout(&["count", "--charset", "01", "--length", "70"]), "1180591620717411303424\n");
+  assert_eq!(stdout(&["count", "--mask", "?l?l?d"]), "6760\n");
+}
+
+#[test]
+fn convert_reads_arguments_or_stdin() {
+  assert_eq!(stdout(&["convert", "--from", "0123456789", "--to", "0123456789ABCDEF", "255", "16"]), "FF\n10\n");
+
+  let mut child = Command::new(env!("CARGO_BIN_EXE_digits"))
+    .args(["convert", "--from", "0123456789", "--to", "01"])
+    .stdin(Stdio::piped()).stdout(Stdio::piped())
+    .spawn().unwrap();
+  child.stdin.take().unwrap().write_all(b"5\n8\n").unwrap();
+  let output = child.wait_with_output().unwrap();
+  assert_eq!(String::from_utf8(output.stdout).unwrap(), "101\n1000\n");
+}
+
+#[test]
+fn calc_does_arithmetic() {
+  assert_eq!(stdout(&["calc", "12", "+", "30"]), "42\n");
+  assert_eq!(stdout(&["calc", "--charset", "0123456789ABCDEF", "FF", "mul", "FF"]), "FE01\n");
+  assert_eq!(stdout(&["calc", "2", "pow", "64"]), "18446744073709551616\n");
+  assert_eq!(stdout(&["calc", "17", "rem", "5"]), "2\n");
+}
+
+#[test]
+fn mask_writes_every_candidate() {
+  assert_eq!(stdout(&["mask", "x?d", "--separator", ","]), "x0,x1,x2,x3,x4,x5,x6,x7,x8,x9,");
+}
+
+#[test]
+fn it_reports_usage_errors() {
+  for args in &[&["calc", "1", "-", "2"][..], &["gen", "--charset", "aa", "--length", "1"], &["mask", "?z"], &["nope"]] {
+    let output = digits(args);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("digits: "));
+  }
+}
+
+#[test]
+fn it_rejects_unknown_options_and_stray_arguments() {
+  for args in &[
+    &["gen", "--chrset", "ab", "--length", "2"][..],
+    &["gen", "--charset", "ab", "--length", "2", "extra"],
+    &["count", "--length", "2", "extra"],
+    &["count", "--mask", "?d", "--charset", "ab"],
+    &["mask", "?d", "--charset", "ab"],
+    &["convert", "--from", "01", "--to", "01", "--base", "2", "1"],
+  ] {
+    let output = digits(args);
+    assert_eq!(output.status.code(), Some(2), "{:?}", args);
+    assert!(output.stdout.is_empty(), "{:?}", args);
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("digits: "), "{:?}", args);
+  }
+}
+
+#[test]
+fn help_works_after_a_command() {
+  for args in &[&["gen", "--help"][..], &["calc", "-h"], &["--help"]] {
+    assert!(stdout(args).starts_with("usage: digits"), "{:?}", args);
+  }
+}
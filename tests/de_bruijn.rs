@@ -0,0 +1,56 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::de_bruijn::DeBruijn;
+use std::collections::HashSet;
+
+#[test]
+fn every_window_appears_exactly_once() {
+  for &(chars, n) in &[("01", 1), ("01", 4), ("abc", 3), ("0123456789", 3), ("0123", 5)] {
+    let sequence = DeBruijn::new(BaseCustom::<char>::new(chars.chars().collect()), n).unwrap();
+    let linear: Vec<char> = sequence.linear().collect();
+    let cycle = chars.len().pow(n as u32);
+    assert_eq!(sequence.iter().count(), cycle);
+    assert_eq!(linear.len(), cycle + n - 1);
+    assert_eq!(sequence.cycle_length().to_s(), cycle.to_string());
+
+    let windows: HashSet<String> = linear.windows(n).map(|w| w.iter().collect()).collect();
+    assert_eq!(windows.len(), cycle, "B({}, {})", chars.len(), n);
+  }
+}
+
+#[test]
+fn pin_pads_take_ten_thousand_and_three_presses() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let sequence = DeBruijn::new(base10, 4).unwrap();
+  let presses: String = sequence.linear().collect();
+  assert_eq!(presses.len(), 10_003);
+  assert!(presses.starts_with("0000100020003"));
+  assert!(presses.contains("1234") && presses.contains("9999"));
+}
+
+#[test]
+fn windows_and_indices_are_inverse() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let sequence = DeBruijn::new(base3.clone(), 3).unwrap();
+  let linear: String = sequence.linear().collect();
+  for index in 0..27 {
+    let window = sequence.window_at(index).unwrap();
+    assert_eq!(window.to_s(), &linear[index as usize..index as usize + 3]);
+    assert_eq!(sequence.index_of(&window).unwrap(), index);
+  }
+  assert_eq!(sequence.index_of(&Digits::new(base3, "b".to_string())).unwrap(), 1);
+}
+
+#[test]
+fn rejects_bad_input() {
+  let base2 = BaseCustom::<char>::new("01".chars().collect());
+  let letters = BaseCustom::<char>::new("ab".chars().collect());
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  assert!(DeBruijn::new(base2.clone(), 0).is_err());
+  let sequence = DeBruijn::new(base2.clone(), 3).unwrap();
+  assert!(sequence.window_at(8).is_err());
+  assert!(sequence.index_of(&Digits::new(base2, "1000".to_string())).is_err());
+  assert!(sequence.index_of(&Digits::new(letters, "a".to_string())).is_err());
+  assert!(DeBruijn::new(base10.clone(), 30).unwrap().window_at(0).is_err());
+  assert_eq!(DeBruijn::new(base10, 30).unwrap().iter().take(5).collect::<String>(), "00000");
+}
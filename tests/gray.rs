@@ -0,0 +1,70 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::gray::GrayIter;
+
+fn differing_places(a: &str, b: &str) -> usize {
+  a.chars().zip(b.chars()).filter(|(x, y)| x != y).count()
+}
+
+#[test]
+fn binary_matches_the_classic_gray_code() {
+  let base2 = BaseCustom::<char>::new("01".chars().collect());
+  let order: Vec<String> = GrayIter::new(base2, 3).unwrap().map(|d| d.to_s()).collect();
+  assert_eq!(order, vec!["000", "001", "011", "010", "110", "111", "101", "100"]);
+}
+
+#[test]
+fn neighbours_differ_in_exactly_one_place_by_one_step() {
+  for &(chars, width) in &[("01", 6), ("abc", 4), ("0123", 3), ("01234", 3), ("0123456789", 3)] {
+    let alphabet: Vec<char> = chars.chars().collect();
+    let order: Vec<String> = GrayIter::new(BaseCustom::<char>::new(chars.chars().collect()), width).unwrap().map(|d| d.to_s()).collect();
+    assert_eq!(order.len(), chars.len().pow(width as u32));
+    for pair in order.windows(2) {
+      assert_eq!(differing_places(&pair[0], &pair[1]), 1, "{} -> {}", pair[0], pair[1]);
+      let (x, y) = pair[0].chars().zip(pair[1].chars()).find(|(x, y)| x != y).unwrap();
+      let step = alphabet.iter().position(|&c| c == x).unwrap() as isize
+        - alphabet.iter().position(|&c| c == y).unwrap() as isize;
+      assert_eq!(step.abs(), 1);
+    }
+    let mut unique = order.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), order.len());
+  }
+}
+
+#[test]
+fn conversion_round_trips() {
+  let base5 = BaseCustom::<char>::new("01234".chars().collect());
+  let mut index = Digits::new(base5.clone(), "0000".to_string());
+  for expected in GrayIter::new(base5, 4).unwrap() {
+    let gray = index.to_gray();
+    assert!(gray.eq_exact(&expected));
+    assert!(gray.from_gray().eq_exact(&index));
+    index.succ_in_place();
+  }
+}
+
+#[test]
+fn padding_does_not_change_the_code() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let short = Digits::new(base10.clone(), "4711".to_string());
+  let padded = Digits::new(base10, "0004711".to_string());
+  assert_eq!(padded.to_gray().to_s(), format!("000{}", short.to_gray().to_s()));
+}
+
+#[test]
+fn iteration_resumes_from_an_index() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let base2 = BaseCustom::<char>::new("ab".chars().collect());
+  let whole: Vec<String> = GrayIter::new(base3.clone(), 3).unwrap().map(|d| d.to_s()).collect();
+  let mut iter = GrayIter::new(base3, 3).unwrap();
+  let mut seen: Vec<String> = iter.by_ref().take(13).map(|d| d.to_s()).collect();
+  let position = iter.position().unwrap();
+  assert_eq!(position.to_s(), "bbb");
+  seen.extend(GrayIter::from_index(&position, 3).unwrap().map(|d| d.to_s()));
+  assert_eq!(seen, whole);
+
+  assert!(GrayIter::new(base2.clone(), 0).is_err());
+  assert!(GrayIter::from_index(&Digits::new(base2, "bab".to_string()), 2).is_err());
+}
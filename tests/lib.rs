@@ -1,3 +1,4 @@
+#![allow(clippy::bool_assert_comparison, clippy::useless_vec)]
 extern crate digits;
 use digits::prelude::*;
 
@@ -18,6 +19,92 @@ fn value_comparison_equal() {
   assert_eq!(b.partial_cmp(&fifteen), Some(Ordering::Equal));
 }
 
+#[test]
+fn value_equality_ignores_zero_padding() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let padded = Digits::new(base10.clone(), "007".to_string());
+  let seven = Digits::new(base10.clone(), "7".to_string());
+  assert_eq!(padded, seven);
+  assert!(!padded.eq_exact(&seven));
+  assert!(padded.eq_exact(&padded.clone()));
+  assert_ne!(seven, Digits::new(base10, "70".to_string()));
+}
+
+#[test]
+fn value_equality_requires_same_mapping() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let a = Digits::new(base10, "7".to_string());
+  let b = Digits::new(base16, "7".to_string());
+  assert_ne!(a, b);
+  assert!(!a.eq_exact(&b));
+}
+
+#[test]
+fn digits_dedupe_in_hash_set() {
+  use std::collections::HashSet;
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut set = HashSet::new();
+  set.insert(Digits::new(base10.clone(), "0042".to_string()));
+  set.insert(Digits::new(base10.clone(), "42".to_string()));
+  set.insert(Digits::new(base10.clone(), "042".to_string()));
+  set.insert(Digits::new(base10.clone(), "0".to_string()));
+  set.insert(Digits::new(base10, "000".to_string()));
+  assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn digits_order_as_btree_map_keys() {
+  use std::collections::BTreeMap;
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut map = BTreeMap::new();
+  map.insert(Digits::new(base10.clone(), "100".to_string()), "c");
+  map.insert(Digits::new(base10.clone(), "0009".to_string()), "a");
+  map.insert(Digits::new(base10.clone(), "10".to_string()), "b");
+  map.insert(Digits::new(base10.clone(), "09".to_string()), "d");
+  assert_eq!(map.len(), 3);
+  assert_eq!(map.values().cloned().collect::<Vec<_>>(), vec!["d", "b", "c"]);
+  assert_eq!(map[&Digits::new(base10, "9".to_string())], "d");
+}
+
+#[test]
+fn try_cmp_errs_for_different_mappings() {
+  use std::cmp::Ordering;
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let a = Digits::new(base10.clone(), "0100".to_string());
+  let b = Digits::new(base10, "99".to_string());
+  let c = Digits::new(base16, "99".to_string());
+  assert_eq!(a.try_cmp(&b), Ok(Ordering::Greater));
+  assert_eq!(b.try_cmp(&a), Ok(Ordering::Less));
+  assert_eq!(a.try_cmp(&c), Err("Incompatible numeric base mappings!"));
+  assert_eq!(a.partial_cmp(&c), None);
+}
+
+#[should_panic(expected = "Incompatible numeric base mappings!")]
+#[test]
+fn ord_panics_for_different_mappings() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let a = Digits::new(base10, "7".to_string());
+  let b = Digits::new(base16, "7".to_string());
+  let _ = a.cmp(&b);
+}
+
+#[test]
+fn cmp_value_across_mappings() {
+  use std::cmp::Ordering;
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let letters = BaseCustom::<char>::new("ABCDEFGHIJ".chars().collect());
+  let dec = Digits::new(base10, "00255".to_string());
+  assert_eq!(dec.cmp_value(&Digits::new(base16.clone(), "FF".to_string())), Ordering::Equal);
+  assert_eq!(dec.cmp_value(&Digits::new(base16.clone(), "100".to_string())), Ordering::Less);
+  assert_eq!(dec.cmp_value(&Digits::new(base16, "0FE".to_string())), Ordering::Greater);
+  assert_eq!(dec.cmp_value(&Digits::new(letters.clone(), "CFF".to_string())), Ordering::Equal);
+  assert_eq!(dec.cmp_value(&Digits::new(letters, "CFG".to_string())), Ordering::Less);
+}
+
 #[test]
 fn up_casting() {
   let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
@@ -528,6 +615,27 @@ fn it_gets_power_of_zero_and_one() {
   assert_eq!(ten.pow(zero).to_s(), "1");
 }
 
+#[test]
+fn power_of_zero_sets_self_to_one() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut padded = Digits::new(base10, "0042".to_string());
+  let zero = padded.zero();
+  assert_eq!(padded.pow(zero).to_s(), "1");
+  assert_eq!(padded.to_s(), "1");
+
+  let letters = BaseCustom::<char>::new("ABCDEFGHIJ".chars().collect());
+  let mut num = Digits::new(letters, "BCD".to_string());
+  let zero = num.zero();
+  assert_eq!(num.pow(zero).to_s(), "B");
+  assert_eq!(num.to_s(), "B");
+
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let mut num = Digits::new(base16, "00FF".to_string());
+  let zero = num.zero();
+  num.pow_in_place(&zero);
+  assert_eq!(num.to_s(), "1");
+}
+
 #[test]
 fn it_can_add_with_plus_symbol(){
   let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
@@ -643,3 +751,262 @@ fn radix_conversion_with_defaults() {
   assert_eq!(fifteen.hexl().to_s(), "f");
   assert_eq!(fifteen.hexl().base(), 16);
 }
+
+#[test]
+fn it_subtracts_with_minus_symbol() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let a = Digits::new(base10.clone(), "0100".to_string());
+  let b = Digits::new(base10, "1".to_string());
+  assert_eq!((a.clone() - b.clone()).to_s(), "0099");
+  assert_eq!((a.clone() - a).to_s(), "0000");
+}
+
+#[should_panic]
+#[test]
+fn it_panics_when_subtracting_below_zero() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let a = Digits::new(base10.clone(), "1".to_string());
+  let b = Digits::new(base10, "2".to_string());
+  let _ = a - b;
+}
+
+#[test]
+fn it_divides_and_finds_remainders() {
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let a = Digits::new(base16.clone(), "FFFF".to_string());
+  let b = Digits::new(base16, "100".to_string());
+  assert_eq!((a.clone() / b.clone()).to_s(), "FF");
+  assert_eq!((a.clone() % b.clone()).to_s(), "FF");
+  assert_eq!((b.clone() / a.clone()).to_s(), "0");
+  assert_eq!((b.clone() % a).to_s(), "100");
+}
+
+#[should_panic]
+#[test]
+fn it_panics_when_dividing_by_zero() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let a = Digits::new(base10.clone(), "1".to_string());
+  let _ = a.clone() / a.zero();
+}
+
+#[test]
+fn it_multiplies_in_other_bases() {
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let ff = Digits::new(base16, "FF".to_string());
+  assert_eq!(ff.mul(ff.clone()).to_s(), "FE01");
+  assert_eq!(ff.clone().mut_mul(ff.clone()).to_s(), "FE01");
+}
+
+#[test]
+fn mut_mul_by_zero_is_zero() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut ten = Digits::new(base10, "10".to_string());
+  let zero = ten.zero();
+  assert!(ten.mut_mul(zero).is_zero());
+}
+
+#[test]
+fn it_handles_a_million_digits_without_overflowing_the_stack() {
+  use digits::Reverse;
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let nines = "9".repeat(1_000_000);
+  let mut num = Digits::new(base10.clone(), nines.clone());
+  assert_eq!(num.length(), 1_000_000);
+  assert!(!num.is_zero());
+  assert_eq!(num.to_s(), nines);
+
+  let copy = num.clone();
+  assert!(copy == num);
+  assert!(num.max_adjacent() == 999_999);
+
+  num.succ();
+  assert_eq!(num.length(), 1_000_001);
+  assert!(num > copy);
+  assert_eq!(num.rcount(0), 1_000_000);
+
+  num.pred_till_zero();
+  assert_eq!(num.to_s(), format!("0{}", nines));
+  assert!(num == copy);
+
+  let sum = copy.clone() + copy.clone();
+  assert_eq!(sum.to_s(), format!("1{}8", "9".repeat(999_999)));
+
+  let mut padded = Digits::new(base10, "7".to_string());
+  padded.zero_fill(1_000_000);
+  assert_eq!(padded.length(), 1_000_000);
+  padded.reverse();
+  assert_eq!(padded.to_s(), format!("7{}", "0".repeat(999_999)));
+}
+
+#[test]
+fn in_place_methods_chain_without_cloning() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let two = Digits::new(base10.clone(), "2".to_string());
+  let mut num = Digits::new(base10.clone(), "0009".to_string());
+
+  num.succ_in_place().add_in_place(&two).pred_till_zero_in_place();
+  assert_eq!(num.to_s(), "0011");
+
+  num.mul_in_place(&two).pow_in_place(&two);
+  assert_eq!(num.to_s(), "484");
+
+  num.pow_in_place(&num.zero());
+  assert_eq!(num.to_s(), "1");
+
+  let mut stepper = Digits::new(base10.clone(), "98".to_string());
+  let mut copy = stepper.clone();
+  stepper.next_non_adjacent_in_place(0).next_non_adjacent_in_place(0);
+  copy.next_non_adjacent(0);
+  assert_eq!(stepper.to_s(), copy.next_non_adjacent(0).to_s());
+}
+
+#[test]
+fn cloning_wrappers_match_in_place_methods() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut a = Digits::new(base10.clone(), "0199".to_string());
+  let mut b = a.clone();
+  assert_eq!(a.succ().to_s(), b.succ_in_place().to_s());
+  assert_eq!(a.pred_till_zero().to_s(), b.pred_till_zero_in_place().to_s());
+  assert_eq!(a.prep_non_adjacent(1).to_s(), b.prep_non_adjacent_in_place(1).to_s());
+  assert_eq!(a.step_non_adjacent(1).to_s(), b.step_non_adjacent_in_place(1).to_s());
+  assert!(a.eq_exact(&b));
+}
+
+#[test]
+fn succ_and_pred_step_through_every_value() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let one = Digits::new_one(base3.clone());
+  let mut up = Digits::new(base3.clone(), "aa".to_string());
+  let mut expected = up.clone();
+  for _ in 0..30 {
+    up.succ_in_place();
+    expected = expected.add(one.clone());
+    assert!(up.eq_exact(&expected), "{} != {}", up, expected);
+  }
+  assert_eq!(up.to_s(), "baba");
+
+  for _ in 0..30 { up.pred_till_zero_in_place(); }
+  assert_eq!(up.to_s(), "aaaa");
+  up.pred_till_zero_in_place();
+  assert_eq!(up.to_s(), "aaaa");
+}
+
+#[test]
+fn digit_sums_and_divisibility_agree_with_u64() {
+  let base7 = BaseCustom::<char>::new("0123456".chars().collect());
+  for n in 0..2000u64 {
+    let num = Digits::from((base7.clone(), n));
+    let mut places = vec![];
+    let mut m = n;
+    while m > 0 { places.push(m % 7); m /= 7; }
+    assert_eq!(num.digit_sum(), places.iter().sum::<u64>());
+
+    let mut root = n;
+    while root >= 7 {
+      let mut sum = 0;
+      while root > 0 { sum += root % 7; root /= 7; }
+      root = sum;
+    }
+    assert_eq!(num.digital_root(), root, "{}", n);
+
+    for divisor in 1..20 {
+      assert_eq!(num.is_divisible_by(divisor), n % divisor == 0, "{} / {}", n, divisor);
+    }
+  }
+}
+
+#[test]
+fn divisibility_of_long_values_by_large_divisors() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base10, format!("{}0", "9".repeat(500)));
+  assert!(num.is_divisible_by(10));
+  assert!(num.is_divisible_by(99));
+  assert!(!num.is_divisible_by(u64::MAX));
+  assert!(num.is_divisible_by(1));
+}
+
+#[test]
+fn palindromes_include_padding() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  assert!(Digits::new(base10.clone(), "7".to_string()).is_palindrome());
+  assert!(Digits::new(base10.clone(), "0110".to_string()).is_palindrome());
+  assert!(!Digits::new(base10.clone(), "011".to_string()).is_palindrome());
+  let mut num = Digits::new(base10, "12321".to_string());
+  assert!(num.is_palindrome());
+  num.succ_in_place();
+  assert!(!num.is_palindrome());
+}
+
+#[test]
+fn shifts_multiply_and_divide_by_base_powers() {
+  let base3 = BaseCustom::<char>::new("012".chars().collect());
+  for n in 0..200u64 {
+    let num = Digits::from((base3.clone(), n));
+    for shift in 0..5usize {
+      let power = 3u64.pow(shift as u32);
+      assert_eq!(num.shl_digits(shift), Digits::from((base3.clone(), n * power)));
+      assert_eq!(num.shr_digits(shift), Digits::from((base3.clone(), n / power)));
+      let (high, low) = num.split_at_position(shift);
+      assert_eq!(high, Digits::from((base3.clone(), n / power)));
+      assert_eq!(low, Digits::from((base3.clone(), n % power)));
+    }
+  }
+}
+
+#[test]
+fn shifting_left_keeps_padding_except_on_zero() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let padded = Digits::new(base10.clone(), "00420".to_string());
+  let padded_zero = Digits::new(base10.clone(), "000".to_string());
+  assert_eq!(padded.shl_digits(3).to_s(), "00420000");
+  assert_eq!(padded_zero.shl_digits(2).to_s(), "0");
+  assert_eq!((padded.clone() << 1).to_s(), "004200");
+  assert_eq!((padded_zero.clone() << 1).to_s(), "0");
+  let zero = Digits::new_zero(base10);
+  assert_eq!((zero.clone() << 3).to_s(), "0");
+  let mut assigned = padded_zero;
+  assigned <<= 4;
+  assert_eq!(assigned.to_s(), "0");
+}
+
+#[test]
+fn shift_operators_match_the_methods() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base10.clone(), "00420".to_string());
+  assert_eq!((num.clone() << 3).to_s(), "00420000");
+  assert_eq!((num.clone() >> 2).to_s(), "004");
+  assert_eq!((num.clone() >> 10).to_s(), "0");
+  let mut assigned = num.clone();
+  assigned <<= 2;
+  assigned >>= 3;
+  assert_eq!(assigned.to_s(), "0042");
+  assert!((num.clone() << 0).eq_exact(&num));
+
+  let (high, low) = num.split_at_position(4);
+  assert_eq!((high.to_s(), low.to_s()), ("0".to_string(), "0420".to_string()));
+  let (high, low) = num.split_at_position(0);
+  assert!(high.eq_exact(&num));
+  assert_eq!(low.to_s(), "0");
+
+  let mut long = Digits::new_one(base10);
+  long <<= 100_000;
+  assert_eq!(long.length(), 100_001);
+  long >>= 99_999;
+  assert_eq!(long.to_s(), "10");
+}
+
+#[test]
+fn long_products_agree_across_karatsuba_thresholds() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let nines = Digits::new(base10.clone(), "9".repeat(3000));
+  let square = nines.clone() * nines.clone();
+  assert_eq!(square.to_s(), format!("{}8{}1", "9".repeat(2999), "0".repeat(2999)));
+
+  let a = Digits::new(base10.clone(), "31415926535897932384626433832795028841971".repeat(40));
+  let b = Digits::new(base10, "27182818284590452353602874713527".repeat(25));
+  let slow = a.mul_with_threshold(&b, usize::MAX);
+  for &threshold in &[0, 2, 7, 32, 1000] {
+    assert!(a.mul_with_threshold(&b, threshold).eq_exact(&slow));
+  }
+  assert!((a * b).eq_exact(&slow));
+}
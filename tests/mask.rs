@@ -0,0 +1,38 @@
+extern crate digits;
+use digits::mask::Mask;
+use digits::writer::CandidateWriter;
+
+#[test]
+fn it_parses_placeholders_and_literals() {
+  let mask = Mask::new("?l?u?d?h?H?s?a??x").unwrap();
+  let sizes: Vec<usize> = mask.positions().iter().map(|set| set.len()).collect();
+  assert_eq!(sizes, vec![26, 26, 10, 16, 16, 33, 95, 1, 1]);
+  assert_eq!(mask.positions()[7], vec!['?']);
+}
+
+#[test]
+fn it_rejects_bad_patterns() {
+  assert!(Mask::new("").is_err());
+  assert!(Mask::new("?q").is_err());
+  assert!(Mask::new("ab?").is_err());
+}
+
+#[test]
+fn it_enumerates_like_an_odometer() {
+  let mask = Mask::new("?d?d").unwrap();
+  let all: Vec<String> = mask.iter().collect();
+  assert_eq!(all.len(), 100);
+  assert_eq!(all[0], "00");
+  assert_eq!(all[42], "42");
+  assert_eq!(all[99], "99");
+  assert_eq!(mask.keyspace().to_s(), "100");
+}
+
+#[test]
+fn the_writer_matches_the_iterator() {
+  let mask = Mask::new("é?h-?d").unwrap();
+  let expected: String = mask.iter().map(|s| format!("{}\n", s)).collect();
+  let mut writer = CandidateWriter::new(vec![]);
+  assert_eq!(writer.write_mask(&mask).unwrap(), 160);
+  assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), expected);
+}
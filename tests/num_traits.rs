@@ -0,0 +1,155 @@
+#![cfg(feature="num-traits")]
+extern crate digits;
+extern crate num_traits;
+use digits::prelude::*;
+use digits::Radix;
+use digits::radices;
+use digits::numeric::{Fixed,Binary,Decimal,Hex};
+use num_traits::{Zero,One,Num,Bounded,CheckedAdd,CheckedMul,Pow,ToPrimitive,FromPrimitive};
+
+fn sum_all<T: Zero + Clone>(values: &[T]) -> T {
+  values.iter().cloned().fold(T::zero(), |acc, v| acc + v)
+}
+
+fn product_all<T: One + Clone>(values: &[T]) -> T {
+  values.iter().cloned().fold(T::one(), |acc, v| acc * v)
+}
+
+fn parse_all<T: Num>(values: &[&str], radix: u32) -> Vec<T> {
+  values.iter().map(|v| T::from_str_radix(v, radix).ok().unwrap()).collect()
+}
+
+#[test]
+fn it_plugs_into_generic_zero_and_one_code() {
+  let values: Vec<Digits> = parse_all(&["12", "30", "0"], 10);
+  assert_eq!(sum_all(&values).to_s(), "42");
+  assert_eq!(product_all(&values[0..2]).to_s(), "360");
+  assert!(Zero::is_zero(&<Digits as Zero>::zero()));
+  assert!(One::is_one(&<Digits as One>::one()));
+}
+
+#[test]
+fn generic_sums_and_products_keep_the_values_alphabet() {
+  let letters = BaseCustom::<char>::new("ABCDEFGHIJ".chars().collect());
+  let values = vec![
+    Digits::new(letters.clone(), "BC".to_string()),
+    Digits::new(letters.clone(), "D".to_string()),
+  ];
+  assert_eq!(sum_all(&values).to_s(), "BF");
+  assert_eq!(product_all(&values).to_s(), "DG");
+  assert_eq!(sum_all(&values[0..1]).to_s(), "BC");
+  assert_eq!(product_all(&values[0..1]).to_s(), "BC");
+
+  let hex: Vec<Digits> = parse_all(&["FF", "1"], 16);
+  assert_eq!(sum_all(&hex).to_s(), "100");
+  assert_eq!(product_all(&hex).to_s(), "FF");
+
+  let empty: Vec<Digits> = vec![];
+  assert_eq!(sum_all(&empty).to_s(), "0");
+  assert_eq!(product_all(&empty).to_s(), "1");
+}
+
+#[test]
+fn identities_take_the_mapping_of_either_operand() {
+  let hex = Digits::from_str_radix("A", 16).unwrap();
+  assert_eq!((hex.clone() + <Digits as Zero>::zero()).to_s(), "A");
+  assert_eq!((hex.clone() * <Digits as One>::one()).to_s(), "A");
+  assert_eq!((<Digits as Zero>::zero() * hex.clone()).to_s(), "0");
+  assert_eq!(hex.checked_add(&<Digits as Zero>::zero()).unwrap().to_s(), "A");
+  assert_eq!(<Digits as One>::one().checked_mul(&hex).unwrap().to_s(), "A");
+  assert_eq!(num_traits::pow(hex, 2).to_s(), "64");
+}
+
+#[test]
+fn identities_compare_and_subtract_in_any_mapping() {
+  use std::cmp::Ordering;
+  let hex5 = Digits::new(radices::hex_base(), "5".to_string());
+  let hex_zero = Digits::new_zero(radices::hex_base());
+  assert!(hex_zero == <Digits as Zero>::zero());
+  assert!(<Digits as Zero>::zero() == hex_zero);
+  assert!(hex5 != <Digits as One>::one());
+  assert_eq!(hex5.partial_cmp(&<Digits as One>::one()), Some(Ordering::Greater));
+  assert_eq!(<Digits as One>::one().cmp(&hex5), Ordering::Less);
+  assert_eq!((hex5.clone() - <Digits as One>::one()).to_s(), "4");
+  assert_eq!((hex5.clone() / <Digits as One>::one()).to_s(), "5");
+  assert_eq!((hex5.clone() % (<Digits as One>::one() + <Digits as One>::one())).to_s(), "1");
+
+  let mut set = std::collections::HashSet::new();
+  set.insert(hex_zero);
+  assert!(set.contains(&<Digits as Zero>::zero()));
+}
+
+#[test]
+fn constants_built_from_identities_take_the_other_mapping() {
+  let one = <Digits as One>::one();
+  let two = one.clone() + one.clone();
+  let ten = (0..8).fold(two.clone(), |acc, _| acc + one.clone());
+  let hex5 = Digits::new(radices::hex_base(), "5".to_string());
+  assert_eq!((hex5.clone() * two.clone()).to_s(), "A");
+  assert_eq!((two.clone() * hex5.clone()).to_s(), "A");
+  assert_eq!((hex5.clone() + ten.clone()).to_s(), "F");
+  assert_eq!((ten.clone() - hex5.clone()).to_s(), "5");
+  assert!(ten == Digits::from_str_radix("A", 16).unwrap());
+  assert_eq!(two.to_s(), "2");
+}
+
+#[test]
+fn from_str_radix_uses_the_standard_radices() {
+  assert_eq!(Digits::from_str_radix("1010", 2).unwrap().decimal().to_s(), "10");
+  assert_eq!(Digits::from_str_radix("17", 8).unwrap().decimal().to_s(), "15");
+  assert_eq!(Digits::from_str_radix("FF", 16).unwrap().decimal().to_s(), "255");
+  assert_eq!(Digits::from_str_radix("ff", 16).unwrap().to_s(), "ff");
+  assert_eq!(Digits::from_str_radix("fF", 16).unwrap().to_s(), "FF");
+  assert_eq!(Digits::from_str_radix("aB12", 16).unwrap().decimal().to_s(), "43794");
+  assert!(Digits::from_str_radix("fG", 16).is_err());
+  assert!(Digits::from_str_radix("12", 2).is_err());
+  assert!(Digits::from_str_radix("12", 36).is_err());
+  assert!(Digits::from_str_radix("", 10).is_err());
+}
+
+#[test]
+fn checked_operations_reject_mismatched_mappings() {
+  let nine = Digits::from_str_radix("9", 10).unwrap();
+  let hex = Digits::from_str_radix("9", 16).unwrap();
+  assert_eq!(nine.checked_add(&nine).unwrap().to_s(), "18");
+  assert_eq!(nine.checked_mul(&nine).unwrap().to_s(), "81");
+  assert!(nine.checked_add(&hex).is_none());
+  assert!(nine.checked_mul(&hex).is_none());
+}
+
+#[test]
+fn it_raises_to_powers() {
+  let two = Digits::from_str_radix("2", 10).unwrap();
+  assert_eq!(Pow::pow(two.clone(), 10usize).to_s(), "1024");
+  assert_eq!(Pow::pow(two.clone(), two.gen(3u64)).to_s(), "8");
+  assert_eq!(num_traits::pow(two, 64).to_s(), "18446744073709551616");
+}
+
+#[test]
+fn it_converts_to_and_from_primitives() {
+  let hex = Digits::from_str_radix("00FF", 16).unwrap();
+  assert_eq!(hex.to_u64(), Some(255));
+  assert_eq!(hex.to_i64(), Some(255));
+  assert_eq!(hex.to_u8(), Some(255));
+  assert_eq!(hex.to_i8(), None);
+  let huge = Digits::from_str_radix("18446744073709551616", 10).unwrap();
+  assert_eq!(huge.to_u64(), None);
+  assert_eq!(Digits::from_u64(1234).unwrap().to_s(), "1234");
+  assert_eq!(Digits::from_i32(77).unwrap().to_s(), "77");
+  assert!(Digits::from_i64(-1).is_none());
+}
+
+#[test]
+fn fixed_widths_are_bounded() {
+  assert_eq!(Fixed::<Decimal, 4>::min_value().digits().to_s(), "0000");
+  assert_eq!(Fixed::<Decimal, 4>::max_value().digits().to_s(), "9999");
+  assert_eq!(Fixed::<Binary, 3>::max_value().into_digits().to_s(), "111");
+  assert_eq!(Fixed::<Hex, 2>::max_value().digits().to_u64(), Some(255));
+  assert!(Fixed::<Hex, 2>::min_value() < Fixed::<Hex, 2>::max_value());
+
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let pin = Fixed::<Decimal, 4>::new(Digits::new(base10.clone(), "000042".to_string())).unwrap();
+  assert_eq!(pin.digits().to_s(), "0042");
+  assert!(Fixed::<Decimal, 4>::new(Digits::new(base10, "12345".to_string())).is_err());
+  assert!(Fixed::<Hex, 4>::new(pin.into_digits()).is_err());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature="rayon")]
+extern crate digits;
+extern crate rayon;
+use digits::prelude::*;
+use digits::mask::Mask;
+use digits::range::DigitsRange;
+use rayon::prelude::*;
+
+fn range(alphabet: &str, start: &str, end: &str) -> DigitsRange {
+  let mapping = BaseCustom::<char>::new(alphabet.chars().collect());
+  DigitsRange::new(
+    Digits::new(mapping.clone(), start.to_string()),
+    Digits::new(mapping, end.to_string())
+  ).unwrap()
+}
+
+#[test]
+fn a_parallel_range_yields_every_value_once() {
+  let r = range("abc", "ab", "cccc");
+  let mut parallel: Vec<String> = r.par_iter().map(|d| d.to_s()).collect();
+  parallel.sort_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+  let sequential: Vec<String> = r.map(|d| d.to_s()).collect();
+  assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn a_parallel_range_handles_single_and_empty_ranges() {
+  assert_eq!(range("01", "1", "1").into_par_iter().count(), 1);
+  assert_eq!(range("01", "11", "1").into_par_iter().count(), 0);
+}
+
+#[test]
+fn find_any_works_beyond_u64() {
+  let r = range("0123456789", "0", &"9".repeat(40));
+  let target = format!("{}1", "0".repeat(39));
+  let found = r.par_iter().find_any(|d| d.to_s() == target || d.to_s() == "1");
+  assert!(found.is_some());
+}
+
+#[test]
+fn a_parallel_mask_yields_every_candidate_once() {
+  let mask = Mask::new("?d-?h?u").unwrap();
+  let mut parallel: Vec<String> = mask.par_iter().collect();
+  parallel.sort();
+  let mut sequential: Vec<String> = mask.iter().collect();
+  sequential.sort();
+  assert_eq!(parallel.len(), 4160);
+  assert_eq!(parallel, sequential);
+  assert_eq!(Mask::new("x").unwrap().into_par_iter().count(), 1);
+}
@@ -0,0 +1,75 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::parallel::{par_for_each, Dispenser};
+use digits::range::DigitsRange;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+fn range(start: &str, end: &str) -> DigitsRange {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  DigitsRange::new(
+    Digits::new(base10.clone(), start.to_string()),
+    Digits::new(base10, end.to_string())
+  ).unwrap()
+}
+
+#[test]
+fn chunks_cover_the_range_exactly_once_across_threads() {
+  let dispenser = Dispenser::new(range("0003", "2000"), 37);
+  let seen = Mutex::new(vec![]);
+  thread::scope(|scope| {
+    for _ in 0..6 {
+      scope.spawn(|| {
+        while let Some(chunk) = dispenser.next_chunk() {
+          let values: Vec<String> = chunk.map(|d| d.to_s()).collect();
+          seen.lock().unwrap().extend(values);
+        }
+      });
+    }
+  });
+  let mut seen = seen.into_inner().unwrap();
+  seen.sort();
+  let expected: Vec<String> = range("0003", "2000").map(|d| d.to_s()).collect();
+  assert_eq!(seen, expected);
+}
+
+#[test]
+fn a_chunk_larger_than_the_range_is_the_whole_range() {
+  let dispenser = Dispenser::new(range("5", "9"), 1_000);
+  let chunk = dispenser.next_chunk().unwrap();
+  assert_eq!(chunk.map(|d| d.to_s()).collect::<Vec<_>>(), vec!["5", "6", "7", "8", "9"]);
+  assert!(dispenser.next_chunk().is_none());
+}
+
+#[test]
+fn stopping_the_dispenser_ends_the_chunks() {
+  let dispenser = Dispenser::new(range("0", "99"), 1);
+  assert!(dispenser.next_chunk().is_some());
+  dispenser.stop();
+  assert!(dispenser.is_stopped());
+  assert!(dispenser.next_chunk().is_none());
+}
+
+#[test]
+fn par_for_each_visits_every_value_without_a_match() {
+  let visited = Mutex::new(HashSet::new());
+  let found = par_for_each(range("000", "999"), 16, 4, |d| {
+    assert!(visited.lock().unwrap().insert(d.to_s()));
+    false
+  });
+  assert!(found.is_none());
+  assert_eq!(visited.into_inner().unwrap().len(), 1000);
+}
+
+#[test]
+fn par_for_each_stops_every_worker_on_a_match() {
+  let calls = AtomicUsize::new(0);
+  let found = par_for_each(range("000000", "999999"), 100, 4, |d| {
+    calls.fetch_add(1, Ordering::Relaxed);
+    d.to_s() == "001234"
+  });
+  assert_eq!(found.unwrap().to_s(), "001234");
+  assert!(calls.load(Ordering::Relaxed) < 100_000);
+}
@@ -0,0 +1,67 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::random::{RandomSource, SplitMix64};
+use digits::range::DigitsRange;
+use std::collections::HashMap;
+
+#[test]
+fn samples_are_uniform_below_the_bound() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let bound = Digits::new(base3, "cb".to_string()); // seven
+  let mut rng = SplitMix64::new(2024);
+  let mut counts: HashMap<String, u32> = HashMap::new();
+  for _ in 0..70_000 {
+    let value = Digits::random_below(&bound, &mut rng).unwrap();
+    assert!(value < bound);
+    *counts.entry(value.to_s()).or_insert(0) += 1;
+  }
+  assert_eq!(counts.len(), 7);
+  for (value, &count) in &counts {
+    assert!(count > 9_500 && count < 10_500, "{} drawn {} times", value, count);
+  }
+}
+
+#[test]
+fn huge_bounds_are_sampled_without_overflow() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let bound = Digits::new(base10, format!("1{}", "0".repeat(200)));
+  let mut rng = SplitMix64::new(9);
+  let lengths: Vec<usize> = (0..50).map(|_| Digits::random_below(&bound, &mut rng).unwrap().length()).collect();
+  assert!(lengths.iter().all(|&l| l <= 200));
+  assert!(lengths.iter().any(|&l| l >= 198));
+}
+
+#[test]
+fn zero_bounds_and_empty_ranges_are_errors() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let zero = Digits::new(base10.clone(), "000".to_string());
+  assert!(Digits::random_below(&zero, &mut SplitMix64::new(1)).is_err());
+
+  let empty = DigitsRange::new(
+    Digits::new(base10.clone(), "5".to_string()), Digits::new(base10, "4".to_string())
+  ).unwrap();
+  assert!(Digits::random_in(&empty, &mut SplitMix64::new(1)).is_err());
+}
+
+#[test]
+fn random_in_covers_the_range_and_keeps_padding() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let range = DigitsRange::new(
+    Digits::new(base10.clone(), "0095".to_string()), Digits::new(base10, "104".to_string())
+  ).unwrap();
+  let mut rng = SplitMix64::new(5);
+  let mut seen: Vec<String> = (0..500).map(|_| Digits::random_in(&range, &mut rng).unwrap().to_s()).collect();
+  seen.sort();
+  seen.dedup();
+  assert_eq!(seen, range.map(|d| d.to_s()).collect::<Vec<_>>());
+}
+
+#[test]
+fn closures_are_random_sources() {
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let bound = Digits::new(base16, "100".to_string());
+  let mut counter = 0_u64;
+  let mut source = || { counter += 1; counter };
+  assert_eq!(source.next_u64(), 1);
+  assert_eq!(Digits::random_below(&bound, &mut source).unwrap().to_s(), "34");
+}
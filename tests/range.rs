@@ -0,0 +1,52 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::range::DigitsRange;
+
+fn base10() -> BaseCustom<char> {
+  BaseCustom::<char>::new("0123456789".chars().collect())
+}
+
+#[test]
+fn it_includes_both_ends_and_keeps_start_padding() {
+  let start = Digits::new(base10(), "008".to_string());
+  let end = Digits::new(base10(), "11".to_string());
+  let values: Vec<String> = DigitsRange::new(start, end).unwrap().map(|d| d.to_s()).collect();
+  assert_eq!(values, vec!["008", "009", "010", "011"]);
+}
+
+#[test]
+fn it_grows_past_the_start_width() {
+  let start = Digits::new(base10(), "8".to_string());
+  let end = Digits::new(base10(), "011".to_string());
+  let values: Vec<String> = DigitsRange::new(start, end).unwrap().map(|d| d.to_s()).collect();
+  assert_eq!(values, vec!["8", "9", "10", "11"]);
+}
+
+#[test]
+fn it_is_empty_when_start_is_past_end() {
+  let start = Digits::new(base10(), "5".to_string());
+  let end = Digits::new(base10(), "4".to_string());
+  let mut range = DigitsRange::new(start, end).unwrap();
+  assert!(range.remaining().is_zero());
+  assert!(range.next().is_none());
+}
+
+#[test]
+fn it_counts_what_remains() {
+  let start = Digits::new(base10(), "7".to_string());
+  let end = Digits::new(base10(), "7".to_string());
+  let mut range = DigitsRange::new(start, end).unwrap();
+  assert_eq!(range.remaining().to_s(), "1");
+  assert_eq!(range.peek().unwrap().to_s(), "7");
+  range.next();
+  assert!(range.peek().is_none());
+  assert_eq!(range.remaining().to_s(), "0");
+}
+
+#[test]
+fn it_rejects_mixed_mappings() {
+  let letters = BaseCustom::<char>::new("abcdefghij".chars().collect());
+  let start = Digits::new(base10(), "1".to_string());
+  let end = Digits::new(letters, "b".to_string());
+  assert!(DigitsRange::new(start, end).is_err());
+}
@@ -0,0 +1,61 @@
+extern crate digits;
+use digits::prelude::*;
+
+#[test]
+fn runs_round_trip() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  for value in &["a", "ab", "aaab", "abbbbbbba", "cabbac"] {
+    let num = Digits::new(base3.clone(), value.to_string());
+    let rebuilt = Digits::from_rle(base3.clone(), &num.to_rle()).unwrap();
+    assert!(rebuilt.eq_exact(&num), "{}", value);
+    let reparsed = Digits::from_rle_str(base3.clone(), &num.to_rle_string()).unwrap();
+    assert!(reparsed.eq_exact(&num), "{}", value);
+  }
+}
+
+#[test]
+fn long_sparse_values_stay_small() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::from_rle_str(base10, "1 0×100000 7").unwrap();
+  assert_eq!(num.length(), 100_002);
+  assert_eq!(num.to_rle(), vec![(1, 1), (0, 100_000), (7, 1)]);
+  assert_eq!(num.to_rle_string(), "1 0×100000 7");
+  let mut next = num.clone();
+  next.succ_in_place();
+  assert_eq!(next.to_rle_string(), "1 0×100000 8");
+}
+
+#[test]
+fn description_accepts_both_separators_and_any_spacing() {
+  let base3 = BaseCustom::<char>::new("*x0".chars().collect());
+  let num = Digits::from_rle_str(base3, "  x*2\t*×2 0×3\n").unwrap();
+  assert_eq!(num.to_s(), "xx**000");
+  assert_eq!(num.to_rle_string(), "x×2 *×2 0×3");
+}
+
+#[test]
+fn rejects_bad_runs() {
+  let base2 = BaseCustom::<char>::new("ab".chars().collect());
+  assert!(Digits::from_rle(base2.clone(), &[]).is_err());
+  assert!(Digits::from_rle(base2.clone(), &[(0, 0)]).is_err());
+  assert!(Digits::from_rle(base2.clone(), &[(2, 1)]).is_err());
+  assert!(Digits::from_rle_str(base2.clone(), "").is_err());
+  assert!(Digits::from_rle_str(base2.clone(), "c").is_err());
+  assert!(Digits::from_rle_str(base2.clone(), "a×").is_err());
+  assert!(Digits::from_rle_str(base2.clone(), "a×0").is_err());
+  assert!(Digits::from_rle_str(base2, "ab").is_err());
+}
+
+#[test]
+fn rejects_run_lengths_too_large_to_expand() {
+  let base2 = BaseCustom::<char>::new("ab".chars().collect());
+  assert_eq!(
+    Digits::from_rle(base2.clone(), &[(0, usize::MAX), (1, 1)]).err(),
+    Some("Run-length encoding is too long to expand!")
+  );
+  assert_eq!(
+    Digits::from_rle_str(base2.clone(), "a×99999999999999").err(),
+    Some("Run-length encoding is too long to expand!")
+  );
+  assert!(Digits::from_rle_str(base2, "a×99999999999999999999999").is_err());
+}
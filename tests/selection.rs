@@ -0,0 +1,106 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::parallel::{par_for_each, Dispenser};
+use digits::selection::Selections;
+
+fn decimal(n: u64) -> Digits {
+  Digits::from((digits::radices::decimal_base(), n))
+}
+
+#[test]
+fn permutations_are_ordered_and_distinct() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let base5 = BaseCustom::<char>::new("abcde".chars().collect());
+  let perms = Selections::permutations(base3, 2).unwrap();
+  let all: Vec<String> = perms.iter().map(|d| d.to_s()).collect();
+  assert_eq!(all, vec!["ab", "ac", "ba", "bc", "ca", "cb"]);
+
+  let full = Selections::permutations(base5, 5).unwrap();
+  let all: Vec<String> = full.iter().map(|d| d.to_s()).collect();
+  assert_eq!(all.len(), 120);
+  assert!(all.windows(2).all(|w| w[0] < w[1]));
+  assert_eq!(full.count().to_s(), "120");
+}
+
+#[test]
+fn combinations_are_ordered_and_sorted() {
+  let base4 = BaseCustom::<char>::new("abcd".chars().collect());
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let combs = Selections::combinations(base4, 2).unwrap();
+  let all: Vec<String> = combs.iter().map(|d| d.to_s()).collect();
+  assert_eq!(all, vec!["ab", "ac", "ad", "bc", "bd", "cd"]);
+  assert_eq!(Selections::combinations(base10, 4).unwrap().iter().count(), 210);
+}
+
+#[test]
+fn rank_and_unrank_match_the_enumeration() {
+  let base7 = BaseCustom::<char>::new("0123456".chars().collect());
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  for selections in [
+    Selections::permutations(base7, 3).unwrap(),
+    Selections::combinations(base10, 4).unwrap(),
+  ] {
+    for (i, value) in selections.iter().enumerate() {
+      assert_eq!(selections.rank(&value).unwrap().to_s(), i.to_string());
+      assert!(selections.unrank(&decimal(i as u64)).unwrap().eq_exact(&value));
+    }
+  }
+}
+
+#[test]
+fn counts_go_beyond_u64() {
+  let alphabet: String = (0..100u32).map(|i| std::char::from_u32(0x100 + i).unwrap()).collect();
+  let mapping = BaseCustom::<char>::new(alphabet.chars().collect());
+  let perms = Selections::permutations(mapping.clone(), 50).unwrap();
+  // 100! / 50!
+  assert_eq!(perms.count().length(), 94);
+  let last = perms.unrank(&(perms.count() - decimal(1))).unwrap();
+  let expected: String = alphabet.chars().rev().take(50).collect();
+  assert_eq!(last.to_s(), expected);
+  assert_eq!(perms.rank(&last).unwrap().to_s(), (perms.count() - decimal(1)).to_s());
+
+  let combs = Selections::combinations(mapping, 50).unwrap();
+  assert_eq!(combs.count().to_s(), "100891344545564193334812497256");
+}
+
+#[test]
+fn ranges_shard_the_enumeration() {
+  let base6 = BaseCustom::<char>::new("abcdef".chars().collect());
+  let perms = Selections::permutations(base6, 3).unwrap();
+  let whole: Vec<String> = perms.iter().map(|d| d.to_s()).collect();
+  let dispenser = Dispenser::new(perms.ranks(), 7);
+  let mut sharded = vec![];
+  while let Some(chunk) = dispenser.next_chunk() {
+    sharded.extend(perms.iter_range(chunk).unwrap().map(|d| d.to_s()));
+  }
+  assert_eq!(sharded, whole);
+
+  let found = par_for_each(perms.ranks(), 10, 3, |rank| perms.unrank(rank).unwrap().to_s() == "fed");
+  assert_eq!(found.unwrap().to_s(), "119");
+}
+
+#[test]
+fn iteration_reports_its_position() {
+  let base5 = BaseCustom::<char>::new("abcde".chars().collect());
+  let combs = Selections::combinations(base5, 3).unwrap();
+  let mut iter = combs.iter();
+  iter.by_ref().take(4).for_each(drop);
+  assert_eq!(iter.position().unwrap().to_s(), "4");
+  assert_eq!(iter.next().unwrap().to_s(), "ace");
+  iter.by_ref().for_each(drop);
+  assert!(iter.position().is_none());
+}
+
+#[test]
+fn rejects_bad_input() {
+  let base2 = BaseCustom::<char>::new("ab".chars().collect());
+  let base4 = BaseCustom::<char>::new("abcd".chars().collect());
+  assert!(Selections::permutations(base2.clone(), 0).is_err());
+  assert!(Selections::combinations(base2, 3).is_err());
+  let combs = Selections::combinations(base4.clone(), 2).unwrap();
+  assert!(combs.rank(&Digits::new(base4.clone(), "ba".to_string())).is_err());
+  assert!(combs.rank(&Digits::new(base4.clone(), "aa".to_string())).is_err());
+  assert!(combs.rank(&Digits::new(base4.clone(), "abc".to_string())).is_err());
+  assert!(combs.unrank(&decimal(6)).is_err());
+  assert!(combs.unrank(&Digits::new(base4, "b".to_string())).is_err());
+}
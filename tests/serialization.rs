@@ -0,0 +1,53 @@
+#![cfg(feature="serde")]
+extern crate digits;
+extern crate serde;
+extern crate serde_json;
+use digits::prelude::*;
+use digits::serialization::Alphabet;
+
+#[test]
+fn digits_round_trip_with_value_string() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base10, "0042".to_string());
+  let json = serde_json::to_string(&num).unwrap();
+  assert_eq!(json, r#"{"alphabet":"0123456789","value":"0042"}"#);
+  let back: Digits = serde_json::from_str(&json).unwrap();
+  assert!(back.eq_exact(&num));
+}
+
+#[test]
+fn digits_round_trip_with_compact_places() {
+  #[derive(serde::Serialize, serde::Deserialize)]
+  struct Checkpoint {
+    #[serde(with = "digits::serialization::compact")]
+    position: Digits,
+  }
+
+  let letters = BaseCustom::<char>::new("abc".chars().collect());
+  let position = Digits::new(letters, "acab".to_string());
+  let json = serde_json::to_string(&Checkpoint { position: position.clone() }).unwrap();
+  assert_eq!(json, r#"{"position":{"alphabet":"abc","places":[0,2,0,1]}}"#);
+  let back: Checkpoint = serde_json::from_str(&json).unwrap();
+  assert!(back.position.eq_exact(&position));
+}
+
+#[test]
+fn alphabet_round_trip() {
+  let base16 = BaseCustom::<char>::new("0123456789ABCDEF".chars().collect());
+  let json = serde_json::to_string(&Alphabet::from(base16.clone())).unwrap();
+  assert_eq!(json, r#""0123456789ABCDEF""#);
+  let back: Alphabet = serde_json::from_str(&json).unwrap();
+  assert!(back.mapping() == &base16);
+}
+
+#[test]
+fn it_rejects_invalid_serialized_digits() {
+  assert!(serde_json::from_str::<Alphabet>(r#""a""#).is_err());
+  assert!(serde_json::from_str::<Alphabet>(r#""abca""#).is_err());
+  assert!(serde_json::from_str::<Digits>(r#"{"alphabet":"01","value":"012"}"#).is_err());
+  assert!(serde_json::from_str::<Digits>(r#"{"alphabet":"01","places":[1,2]}"#).is_err());
+  assert!(serde_json::from_str::<Digits>(r#"{"alphabet":"01","value":""}"#).is_err());
+  assert!(serde_json::from_str::<Digits>(r#"{"alphabet":"01","places":[]}"#).is_err());
+  assert!(serde_json::from_str::<Digits>(r#"{"alphabet":"01"}"#).is_err());
+  assert!(serde_json::from_str::<Digits>(r#"{"alphabet":"01","value":"1","places":[1]}"#).is_err());
+}
@@ -0,0 +1,115 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::mask::Mask;
+use digits::range::DigitsRange;
+use digits::session::Session;
+use std::io;
+use std::path::PathBuf;
+
+fn checkpoint(name: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!("digits-session-test-{}-{}", std::process::id(), name));
+  let _ = std::fs::remove_file(&path);
+  path
+}
+
+fn range(alphabet: &str, start: &str, end: &str) -> DigitsRange {
+  let mapping = BaseCustom::<char>::new(alphabet.chars().collect());
+  DigitsRange::new(
+    Digits::new(mapping.clone(), start.to_string()),
+    Digits::new(mapping, end.to_string())
+  ).unwrap()
+}
+
+fn drain(session: &mut Session) -> Vec<String> {
+  let mut all = vec![];
+  while let Some(candidate) = session.next_candidate().unwrap() { all.push(candidate); }
+  all
+}
+
+#[test]
+fn a_stopped_run_resumes_after_the_last_candidate() {
+  let path = checkpoint("stopped");
+  let full: Vec<String> = range("abc", "aaa", "ccc").map(|d| d.to_s()).collect();
+
+  let mut seen = vec![];
+  let mut session = Session::range(range("abc", "aaa", "ccc")).checkpoint(&path, 4);
+  assert!(!session.run(|c| { seen.push(c.to_string()); c != "bab" }).unwrap());
+
+  let mut resumed = Session::restore(&path).unwrap();
+  assert_eq!(resumed.emitted() as usize, seen.len());
+  assert!(resumed.run(|c| { seen.push(c.to_string()); true }).unwrap());
+  assert_eq!(seen, full);
+  assert_eq!(Session::restore(&path).unwrap().emitted(), 27);
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn a_crash_repeats_only_candidates_since_the_last_save() {
+  let path = checkpoint("crash");
+  let full: Vec<String> = range("0123456789", "00", "99").map(|d| d.to_s()).collect();
+
+  let mut session = Session::range(range("0123456789", "00", "99")).checkpoint(&path, 7);
+  for _ in 0..20 { session.next_candidate().unwrap(); }
+  drop(session);
+
+  let mut resumed = Session::restore(&path).unwrap();
+  assert_eq!(resumed.emitted(), 14);
+  let rest = drain(&mut resumed);
+  assert_eq!(&full[14..], &rest[..]);
+  assert!(resumed.is_done());
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mask_sessions_keep_their_constraints() {
+  let path = checkpoint("mask");
+  let expected: Vec<String> = Mask::new("?d?d?d").unwrap().iter()
+    .filter(|c| { let b = c.as_bytes(); b[0] != b[1] && b[1] != b[2] })
+    .collect();
+
+  let mut session = Session::mask(Mask::new("?d?d?d").unwrap()).max_adjacent(0).checkpoint(&path, 0);
+  let mut seen = vec![];
+  session.run(|c| { seen.push(c.to_string()); seen.len() < 100 }).unwrap();
+
+  let mut resumed = Session::restore(&path).unwrap();
+  seen.extend(drain(&mut resumed));
+  assert_eq!(seen, expected);
+
+  resumed.save().unwrap();
+  let mut finished = Session::restore(&path).unwrap();
+  assert!(finished.is_done());
+  assert_eq!(finished.next_candidate().unwrap(), None);
+  assert_eq!(finished.emitted() as usize, expected.len());
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn unusual_alphabets_survive_a_checkpoint() {
+  let path = checkpoint("alphabet");
+  let alphabet = "\"\\\n é\u{301}";
+  let full: Vec<String> = range(alphabet, "\"\"", "é\u{301}").map(|d| d.to_s()).collect();
+
+  let mut session = Session::range(range(alphabet, "\"\"", "é\u{301}")).checkpoint(&path, 0);
+  let mut seen = vec![];
+  session.run(|c| { seen.push(c.to_string()); seen.len() < 9 }).unwrap();
+  seen.extend(drain(&mut Session::restore(&path).unwrap()));
+  assert_eq!(seen, full);
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_rejects_corrupt_checkpoints() {
+  let path = checkpoint("corrupt");
+  for text in &[
+    "not a checkpoint\n",
+    "digits-session 1\nkind \"range\"\nend \"9\"\n",
+    "digits-session 1\nkind \"range\"\nalphabet \"01\"\nnext \"2\"\nend \"1\"\n",
+    "digits-session 1\nkind \"range\"\nalphabet \"00\"\nend \"0\"\n",
+    "digits-session 1\nkind \"mask\"\nmask \"?d\"\nnext 10\n",
+    "digits-session 1\nkind \"range\"\nalphabet \"01\nend \"1\"\n",
+  ] {
+    std::fs::write(&path, text).unwrap();
+    assert_eq!(Session::restore(&path).unwrap_err().kind(), io::ErrorKind::InvalidData, "{}", text);
+  }
+  std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,76 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::shuffle::Shuffle;
+
+fn all_values(shuffle: &Shuffle) -> Vec<String> {
+  let mut values: Vec<String> = shuffle.iter().map(|d| d.to_s()).collect();
+  values.sort();
+  values
+}
+
+#[test]
+fn every_value_is_visited_exactly_once() {
+  for &(chars, length) in &[("01", 1), ("01", 5), ("abc", 1), ("abc", 4), ("0123456789", 3), ("0123456789ABCDEF", 2)] {
+    let mapping = BaseCustom::<char>::new(chars.chars().collect());
+    let shuffle = Shuffle::new(mapping.clone(), length, 17).unwrap();
+    let values = all_values(&shuffle);
+    let mut expected = vec![];
+    let size = chars.len().pow(length as u32);
+    let zero: String = chars.chars().next().unwrap().to_string().repeat(length);
+    let mut value = Digits::new(mapping, zero);
+    for _ in 0..size {
+      expected.push(value.to_s());
+      value.succ_in_place();
+    }
+    assert_eq!(values, expected, "{} places of {}", length, chars);
+  }
+}
+
+#[test]
+fn invert_undoes_permute() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let shuffle = Shuffle::new(base10.clone(), 40, 99).unwrap();
+  let index = Digits::new(base10, "12345".to_string());
+  let value = shuffle.permute(&index).unwrap();
+  assert_eq!(value.length(), 40);
+  assert_eq!(shuffle.invert(&value).unwrap().to_s(), format!("{}12345", "0".repeat(35)));
+}
+
+#[test]
+fn keys_give_different_orders() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let a: Vec<String> = Shuffle::new(base10.clone(), 3, 1).unwrap().iter().take(10).map(|d| d.to_s()).collect();
+  let b: Vec<String> = Shuffle::new(base10.clone(), 3, 2).unwrap().iter().take(10).map(|d| d.to_s()).collect();
+  assert_ne!(a, b);
+  let again: Vec<String> = Shuffle::new(base10, 3, 1).unwrap().iter().take(10).map(|d| d.to_s()).collect();
+  assert_eq!(a, again);
+}
+
+#[test]
+fn iteration_resumes_from_its_position() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let shuffle = Shuffle::new(base3, 3, 5).unwrap();
+  let whole: Vec<String> = shuffle.iter().map(|d| d.to_s()).collect();
+  let mut iter = shuffle.iter();
+  let mut first: Vec<String> = iter.by_ref().take(10).map(|d| d.to_s()).collect();
+  let position = iter.position().unwrap();
+  assert_eq!(position.to_s(), "bab");
+  first.extend(shuffle.iter_from(&position).unwrap().map(|d| d.to_s()));
+  assert_eq!(first, whole);
+
+  let mut done = shuffle.iter();
+  done.by_ref().for_each(drop);
+  assert!(done.position().is_none());
+}
+
+#[test]
+fn rejects_bad_input() {
+  let base2 = BaseCustom::<char>::new("01".chars().collect());
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  assert!(Shuffle::new(base2.clone(), 0, 1).is_err());
+  let shuffle = Shuffle::new(base10.clone(), 2, 1).unwrap();
+  assert!(shuffle.permute(&Digits::new(base10.clone(), "100".to_string())).is_err());
+  assert!(shuffle.permute(&Digits::new(base2, "1".to_string())).is_err());
+  assert!(shuffle.permute(&Digits::new(base10, "0099".to_string())).is_ok());
+  assert_eq!(shuffle.keyspace().to_s(), "100");
+}
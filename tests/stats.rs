@@ -0,0 +1,45 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::stats::CharClasses;
+
+#[test]
+fn histogram_counts_padding_too() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let mut num = Digits::new(base10, "7".to_string());
+  num.zero_fill(4);
+  let histogram = num.histogram();
+  assert_eq!(histogram.len(), 10);
+  assert_eq!((histogram[0], histogram[7]), (3, 1));
+  assert_eq!(histogram.iter().sum::<usize>(), 4);
+  assert_eq!(num.distinct(), 2);
+}
+
+#[test]
+fn runs_cover_the_whole_value() {
+  let base2 = BaseCustom::<char>::new("ab".chars().collect());
+  let num = Digits::new(base2.clone(), "aaabbbbab".to_string());
+  assert_eq!(num.runs(), vec![('a', 3), ('b', 4), ('a', 1), ('b', 1)]);
+  assert_eq!(Digits::new(base2, "b".to_string()).runs(), vec![('b', 1)]);
+  let longest = num.runs().iter().map(|r| r.1).max().unwrap();
+  assert_eq!(longest - 1, num.max_adjacent());
+}
+
+#[test]
+fn entropy_rises_with_spread() {
+  let hex = BaseCustom::<char>::new("0123456789abcdef".chars().collect());
+  let flat = Digits::new(hex.clone(), "0123456789abcdef".to_string()).entropy();
+  assert!((flat - 4.0).abs() < 1e-12);
+  let skewed = Digits::new(hex.clone(), "0000000f".to_string()).entropy();
+  assert!(skewed > 0.5 && skewed < 0.6);
+  assert_eq!(Digits::new(hex, "0".to_string()).entropy(), 0.0);
+}
+
+#[test]
+fn classes_follow_the_mask_placeholders() {
+  let base6 = BaseCustom::<char>::new("aZ5 ~é".chars().collect());
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let num = Digits::new(base6, "aaZ5 ~éé".to_string());
+  assert_eq!(num.char_classes(), CharClasses { lower: 2, upper: 1, digit: 1, special: 2, other: 2 });
+  assert_eq!(num.char_classes().variety(), 5);
+  assert_eq!(Digits::new(base10, "42".to_string()).char_classes().variety(), 1);
+}
@@ -0,0 +1,45 @@
+extern crate digits;
+use digits::prelude::*;
+use digits::range::DigitsRange;
+use digits::writer::CandidateWriter;
+
+fn written(writer: CandidateWriter<Vec<u8>>) -> String {
+  String::from_utf8(writer.into_inner()).unwrap()
+}
+
+#[test]
+fn it_writes_a_range_one_per_line() {
+  let base3 = BaseCustom::<char>::new("abc".chars().collect());
+  let start = Digits::new(base3.clone(), "bc".to_string());
+  let end = Digits::new(base3, "bac".to_string());
+  let mut writer = CandidateWriter::new(vec![]);
+  assert_eq!(writer.write_range(DigitsRange::new(start, end).unwrap()).unwrap(), 7);
+  assert_eq!(written(writer), "bc\nca\ncb\ncc\nbaa\nbab\nbac\n");
+}
+
+#[test]
+fn it_matches_to_s_with_multibyte_characters_and_affixes() {
+  let mixed = BaseCustom::<char>::new("aβ€😀".chars().collect());
+  let start = Digits::new(mixed.clone(), "a😀".to_string());
+  let end = Digits::new(mixed, "β€😀".to_string());
+  let range = DigitsRange::new(start, end).unwrap();
+
+  let expected: String = range.clone().map(|d| format!("<{}>|", d.to_s())).collect();
+  let mut writer = CandidateWriter::new(vec![]).prefix("<").suffix(">").separator("|");
+  writer.write_range(range.clone()).unwrap();
+  assert_eq!(written(writer), expected);
+
+  let mut writer = CandidateWriter::new(vec![]).prefix("<").suffix(">").separator("|");
+  assert_eq!(writer.write_iter(range).unwrap(), 25);
+  assert_eq!(written(writer), expected);
+}
+
+#[test]
+fn it_writes_nothing_for_an_empty_range() {
+  let base10 = BaseCustom::<char>::new("0123456789".chars().collect());
+  let start = Digits::new(base10.clone(), "2".to_string());
+  let end = Digits::new(base10, "1".to_string());
+  let mut writer = CandidateWriter::new(vec![]);
+  assert_eq!(writer.write_range(DigitsRange::new(start, end).unwrap()).unwrap(), 0);
+  assert_eq!(written(writer), "");
+}